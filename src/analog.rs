@@ -0,0 +1,69 @@
+//! Coefficients derived from analog component values.
+
+use core::f32::consts::PI;
+
+use crate::{math, FilterCoefficients, FilterType, MAX_FREQ_RATIO};
+
+impl FilterCoefficients {
+    /// Returns the coefficients of a passive RC low-pass with resistance `r`
+    /// in ohms and capacitance `c` in farads.
+    ///
+    /// The transfer function `1 / (1 + s * R * C)` is transformed with the
+    /// bilinear transform, prewarped at the corner frequency
+    /// `1 / (2 * PI * R * C)`. The digital response matches the analog one
+    /// exactly there and is increasingly compressed towards the Nyquist
+    /// frequency.
+    ///
+    /// Corner frequencies at or above the Nyquist frequency can't be matched.
+    /// In this case, the prewarp frequency is limited to just below the
+    /// Nyquist frequency, which keeps the filter stable for all positive
+    /// component values, with a response compressed into the available
+    /// range.
+    ///
+    /// `sample_time` is `1.0 / sample_rate`.
+    pub fn from_rc_lowpass(r: f32, c: f32, sample_time: f32) -> FilterCoefficients {
+        let freq = 1.0 / (2.0 * PI * r * c);
+        Self::from_analog(FilterType::FirstOrderLowPass { freq }, freq, sample_time)
+    }
+
+    /// Returns the coefficients of a passive RC high-pass with resistance `r`
+    /// in ohms and capacitance `c` in farads.
+    ///
+    /// The transfer function `s * R * C / (1 + s * R * C)` is transformed as
+    /// described for [`FilterCoefficients::from_rc_lowpass`].
+    ///
+    /// `sample_time` is `1.0 / sample_rate`.
+    pub fn from_rc_highpass(r: f32, c: f32, sample_time: f32) -> FilterCoefficients {
+        let freq = 1.0 / (2.0 * PI * r * c);
+        Self::from_analog(FilterType::FirstOrderHighPass { freq }, freq, sample_time)
+    }
+
+    /// Returns the coefficients of a series RLC circuit with resistance `r`
+    /// in ohms, inductance `l` in henries and capacitance `c` in farads,
+    /// taking the output across the capacitor.
+    ///
+    /// The transfer function `1 / (1 + s * R * C + s^2 * L * C)` is a
+    /// second-order low-pass with resonance frequency `1 / (2 * PI * sqrt(L * C))`
+    /// and Q `sqrt(L / C) / R`. It is transformed with the bilinear
+    /// transform, prewarped at the resonance frequency, which is limited to
+    /// just below the Nyquist frequency as described for
+    /// [`FilterCoefficients::from_rc_lowpass`].
+    ///
+    /// `sample_time` is `1.0 / sample_rate`.
+    pub fn from_rlc(r: f32, l: f32, c: f32, sample_time: f32) -> FilterCoefficients {
+        let freq = 1.0 / (2.0 * PI * math::sqrt(l * c));
+        let q = math::sqrt(l / c) / r;
+        Self::from_analog(FilterType::LowPass { freq, q }, freq, sample_time)
+    }
+
+    /// Calculates the coefficients of an analog prototype with the bilinear
+    /// transform prewarped at `freq` in Hz, limited to below the Nyquist
+    /// frequency.
+    fn from_analog(filter_type: FilterType, freq: f32, sample_time: f32) -> FilterCoefficients {
+        Self::from_type_prewarp_at(
+            filter_type,
+            sample_time,
+            freq.min(MAX_FREQ_RATIO / sample_time),
+        )
+    }
+}
@@ -0,0 +1,274 @@
+//! Cascades of biquad sections.
+
+use crate::response::{refined_maximum, SCAN_POINTS};
+use crate::{math, DirectForm2Transposed, FilterCoefficients};
+
+/// Series connection of `N` biquad sections.
+///
+/// Each section is followed by a scalar gain, which can be used to manage
+/// headroom of the intermediate signals.
+#[derive(Debug, Clone)]
+pub struct Cascade<const N: usize> {
+    /// Filter sections.
+    sections: [DirectForm2Transposed; N],
+
+    /// Gains applied to the output of each section.
+    scales: [f32; N],
+}
+
+/// Returns the number of sections needed for a filter of order `order`.
+///
+/// Odd orders require an additional first-order section, see
+/// [`has_first_order_section`]. Being a `const fn`, the result can be used
+/// as size of a [`Cascade`].
+pub const fn sections_for_order(order: usize) -> usize {
+    order.div_ceil(2)
+}
+
+/// Returns if a filter of order `order` requires a first-order section.
+pub const fn has_first_order_section(order: usize) -> bool {
+    order % 2 == 1
+}
+
+/// Number of sections returned by [`halfband_sections`].
+pub const HALFBAND_SECTIONS: usize = 4;
+
+/// Returns the sections of a low-pass filter for 2x resampling.
+///
+/// The filter is an 8th order elliptic design with a passband up to 20% of
+/// the sample rate and a stopband starting at a quarter of the sample rate.
+/// It is used as anti-aliasing filter before discarding every 2nd sample
+/// and as anti-imaging filter after inserting zeros between samples.
+///
+/// Achieved response:
+///
+/// - Passband ripple: 0.1 dB, with the peaks at 0 dB.
+/// - Stopband attenuation: 78 dB.
+///
+/// The response is defined relative to the sample rate the filter runs at,
+/// which is the higher of the two rates, so no sample time is required.
+/// The sections are ordered by increasing Q for best headroom.
+pub const fn halfband_sections() -> [FilterCoefficients; HALFBAND_SECTIONS] {
+    [
+        FilterCoefficients::from_raw(
+            0.070_261_72,
+            0.126_220_85,
+            0.070_261_72,
+            -1.063_859_1,
+            0.333_692_2,
+        ),
+        FilterCoefficients::from_raw(
+            0.234_939_6,
+            0.205_113_62,
+            0.234_939_6,
+            -0.857_525_17,
+            0.532_518,
+        ),
+        FilterCoefficients::from_raw(
+            0.487_959_5,
+            0.129_032_55,
+            0.487_959_5,
+            -0.649_904_2,
+            0.754_855_75,
+        ),
+        FilterCoefficients::from_raw(0.676_692_6, 0.017_862_488, 0.676_692_6, -0.553_752_3, 0.925),
+    ]
+}
+
+impl<const N: usize> Default for Cascade<N> {
+    fn default() -> Self {
+        Self {
+            sections: core::array::from_fn(|_| DirectForm2Transposed::new()),
+            scales: [1.0; N],
+        }
+    }
+}
+
+impl<const N: usize> Cascade<N> {
+    /// Returns a new instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resets all sections to bypass mode and their scales to unity.
+    pub fn reset(&mut self) {
+        for section in self.sections.iter_mut() {
+            section.reset();
+        }
+        self.scales = [1.0; N];
+    }
+
+    /// Sets the coefficients of section `idx`.
+    pub fn set_coefficients(&mut self, idx: usize, coeffs: FilterCoefficients) {
+        self.sections[idx].set_coefficients(coeffs);
+    }
+
+    /// Sets the gain applied to the output of section `idx`.
+    pub fn set_section_scale(&mut self, idx: usize, scale: f32) {
+        self.scales[idx] = scale;
+    }
+
+    /// Returns the gain applied to the output of section `idx`.
+    pub fn section_scale(&self, idx: usize) -> f32 {
+        self.scales[idx]
+    }
+
+    /// Distributes the gain across the sections so that the magnitude
+    /// response up to each intermediate output peaks at unity.
+    ///
+    /// This is the L-infinity scaling approach: it guarantees that a
+    /// sinusoid with an amplitude not exceeding 1.0 never exceeds 1.0
+    /// between sections. The more aggressive L2 approach, which bounds the
+    /// energy instead of the peak, is suited for broadband signals but can
+    /// clip on narrowband input and is therefore not used here.
+    ///
+    /// The scale of the last section compensates the others, so the overall
+    /// response of the cascade is unchanged. The peaks of the response are
+    /// located by scanning and refining the local maxima of the scan.
+    ///
+    /// `sample_time` is `1.0 / sample_rate`.
+    pub fn balance_scales(&mut self, sample_time: f32) {
+        let mut prev_peak = 1.0;
+
+        for idx in 0..N {
+            if idx == N - 1 {
+                self.scales[idx] = prev_peak;
+                break;
+            }
+
+            let peak = math::sqrt(refined_maximum(sample_time, |freq| {
+                self.sections[..=idx]
+                    .iter()
+                    .map(|section| section.coeffs.magnitude_squared_at(freq, sample_time))
+                    .product()
+            }));
+
+            if peak > 0.0 {
+                self.scales[idx] = prev_peak / peak;
+                prev_peak = peak;
+            } else {
+                self.scales[idx] = 1.0;
+            }
+        }
+    }
+
+    /// Sets the coefficients and scales of `out` to the linear interpolation
+    /// between `self` at `t = 0.0` and `other` at `t = 1.0`, e.g. to morph
+    /// between two snapshots of an EQ.
+    ///
+    /// Sections are interpolated pairwise by index and `t` is clamped to
+    /// `0.0..=1.0`. The state of `out` is kept, so it can be updated while
+    /// processing.
+    ///
+    /// Interpolating between two stable sections always results in a stable
+    /// section, because the region of stable feedback coefficients is a
+    /// triangle and therefore convex. However, the intermediate responses are
+    /// not necessarily in between the two endpoints: peaks can dip or bulge
+    /// on the way, especially if the corresponding sections differ a lot in
+    /// frequency or are not ordered the same way. Changing `t` quickly
+    /// causes the same artifacts as any fast coefficient modulation.
+    ///
+    /// If this is a problem, process the signal with both cascades and mix
+    /// the outputs instead, see [`Cascade::process_block_crossfaded`].
+    pub fn crossfade_into(&self, other: &Cascade<N>, t: f32, out: &mut Cascade<N>) {
+        let t = t.clamp(0.0, 1.0);
+
+        for idx in 0..N {
+            out.set_coefficients(
+                idx,
+                self.sections[idx]
+                    .coeffs
+                    .lerp(&other.sections[idx].coeffs, t),
+            );
+            out.scales[idx] = self.scales[idx] + (other.scales[idx] - self.scales[idx]) * t;
+        }
+    }
+
+    /// Returns the magnitude response of the whole cascade at `freq` in Hz
+    /// in dB, including the section scales.
+    ///
+    /// `sample_time` is `1.0 / sample_rate`.
+    pub fn magnitude_db_at(&self, freq: f32, sample_time: f32) -> f32 {
+        self.sections
+            .iter()
+            .zip(self.scales.iter())
+            .map(|(section, scale)| {
+                section.coeffs.magnitude_db_at(freq, sample_time) + 20.0 * math::log10(scale.abs())
+            })
+            .sum()
+    }
+
+    /// Returns the peak-to-peak ripple of the magnitude response in dB
+    /// between `f_low` and `f_high` in Hz.
+    ///
+    /// The range is scanned at a fixed number of linearly spaced
+    /// frequencies, including both ends.
+    ///
+    /// `sample_time` is `1.0 / sample_rate`.
+    pub fn passband_ripple_db(&self, f_low: f32, f_high: f32, sample_time: f32) -> f32 {
+        let (min, max) = (0..SCAN_POINTS)
+            .map(|i| {
+                let freq = f_low + (f_high - f_low) * i as f32 / (SCAN_POINTS - 1) as f32;
+                self.magnitude_db_at(freq, sample_time)
+            })
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), db| {
+                (min.min(db), max.max(db))
+            });
+
+        max - min
+    }
+
+    /// Processes a single sample.
+    pub fn process_sample(&mut self, sample: f32) -> f32 {
+        self.sections
+            .iter_mut()
+            .zip(self.scales.iter())
+            .fold(sample, |sample, (section, scale)| {
+                section.process_sample(sample) * scale
+            })
+    }
+
+    /// Processes a block of samples in-place.
+    pub fn process_block(&mut self, samples: &mut [f32]) {
+        for sample in samples.iter_mut() {
+            *sample = self.process_sample(*sample);
+        }
+    }
+
+    /// Processes a block of samples and adds the result to `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` and `out` differ in length.
+    pub fn process_block_add(&mut self, input: &[f32], out: &mut [f32]) {
+        assert_eq!(input.len(), out.len());
+
+        for (sample, out_sample) in input.iter().zip(out.iter_mut()) {
+            *out_sample += self.process_sample(*sample);
+        }
+    }
+
+    /// Processes a block of samples in-place with both `self` and `other`
+    /// and mixes their outputs, with `self` at `t = 0.0` and `other` at
+    /// `t = 1.0`.
+    ///
+    /// Unlike [`Cascade::crossfade_into`], this is stable for any `t` and
+    /// free of artifacts when changing it quickly, at the cost of running
+    /// both cascades. As the outputs are summed, the phase differences
+    /// between them can cause comb filtering in between the endpoints.
+    /// `t` is clamped to `0.0..=1.0`.
+    pub fn process_block_crossfaded(
+        &mut self,
+        other: &mut Cascade<N>,
+        t: f32,
+        samples: &mut [f32],
+    ) {
+        let t = t.clamp(0.0, 1.0);
+
+        for sample in samples.iter_mut() {
+            let a = self.process_sample(*sample);
+            let b = other.process_sample(*sample);
+            *sample = a + (b - a) * t;
+        }
+    }
+}
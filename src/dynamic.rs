@@ -0,0 +1,121 @@
+//! Level-dependent filter.
+
+use micromath::F32Ext;
+
+use crate::{DirectForm2Transposed, FilterCoefficients};
+
+/// Initial attack time of the envelope follower in seconds.
+const DEFAULT_ATTACK: f32 = 0.001;
+
+/// Initial release time of the envelope follower in seconds.
+const DEFAULT_RELEASE: f32 = 0.05;
+
+/// Filter blended in depending on the level of the input signal.
+///
+/// An envelope follower tracks the peak level of the input. While it stays
+/// below the threshold, the signal passes unprocessed. Above it, the filtered
+/// signal is blended in, e.g. a high-shelf cut for de-essing or as a band of
+/// a dynamic EQ.
+#[derive(Debug, Clone)]
+pub struct DynamicFilter {
+    /// Filter applied above the threshold.
+    filter: DirectForm2Transposed,
+
+    /// Sample time in seconds.
+    sample_time: f32,
+
+    /// Smoothing coefficient for a rising envelope.
+    attack_coeff: f32,
+
+    /// Smoothing coefficient for a falling envelope.
+    release_coeff: f32,
+
+    /// Current envelope level.
+    envelope: f32,
+}
+
+impl DynamicFilter {
+    /// Returns a new instance.
+    ///
+    /// `sample_time` is `1.0 / sample_rate`.
+    pub fn new(sample_time: f32) -> Self {
+        let mut filter = Self {
+            filter: DirectForm2Transposed::new(),
+            sample_time,
+            attack_coeff: 0.0,
+            release_coeff: 0.0,
+            envelope: 0.0,
+        };
+        filter.set_attack(DEFAULT_ATTACK);
+        filter.set_release(DEFAULT_RELEASE);
+        filter
+    }
+
+    /// Resets the filter to bypass mode and clears the envelope.
+    pub fn reset(&mut self) {
+        self.set_coefficients(FilterCoefficients::default());
+        self.envelope = 0.0;
+    }
+
+    /// Sets the coefficients.
+    pub fn set_coefficients(&mut self, coeffs: FilterCoefficients) {
+        self.filter.set_coefficients(coeffs);
+    }
+
+    /// Sets the attack time of the envelope follower in seconds.
+    pub fn set_attack(&mut self, attack: f32) {
+        self.attack_coeff = self.smoothing_coeff(attack);
+    }
+
+    /// Sets the release time of the envelope follower in seconds.
+    pub fn set_release(&mut self, release: f32) {
+        self.release_coeff = self.smoothing_coeff(release);
+    }
+
+    /// Returns the current envelope level.
+    pub fn envelope(&self) -> f32 {
+        self.envelope
+    }
+
+    /// Processes a block of samples in-place, blending between the dry and
+    /// the filtered signal per sample.
+    ///
+    /// The amount of the filtered signal follows the gain reduction of a
+    /// compressor with the same `threshold` and `ratio`: it is 0.0 up to the
+    /// threshold and approaches 1.0 as the envelope exceeds it. A `ratio` of
+    /// 1.0 never engages the filter.
+    ///
+    /// `threshold` is a linear level and clamped to be non-negative, `ratio`
+    /// is clamped to be at least 1.0.
+    pub fn process_block_ducked(&mut self, samples: &mut [f32], threshold: f32, ratio: f32) {
+        let threshold = threshold.max(0.0);
+        let exponent = 1.0 - 1.0 / ratio.max(1.0);
+
+        for sample in samples.iter_mut() {
+            let level = sample.abs();
+            let coeff = if level > self.envelope {
+                self.attack_coeff
+            } else {
+                self.release_coeff
+            };
+            self.envelope = level + coeff * (self.envelope - level);
+
+            // The filter keeps running while disengaged to avoid transients.
+            let filtered = self.filter.process_sample(*sample);
+
+            if self.envelope > threshold {
+                let blend = 1.0 - F32Ext::powf(threshold / self.envelope, exponent);
+                *sample += (filtered - *sample) * blend;
+            }
+        }
+    }
+
+    /// Returns the smoothing coefficient for a time constant in seconds.
+    fn smoothing_coeff(&self, time: f32) -> f32 {
+        if time > 0.0 {
+            F32Ext::exp(-self.sample_time / time)
+        } else {
+            0.0
+        }
+    }
+}
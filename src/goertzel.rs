@@ -0,0 +1,69 @@
+//! Goertzel single-frequency detector.
+
+use crate::math;
+use crate::response::unit_circle;
+
+/// Goertzel detector measuring the energy of a single frequency bin.
+///
+/// Samples are accumulated via [`Goertzel::process_sample`] or
+/// [`Goertzel::process_block`] and the result is read with
+/// [`Goertzel::magnitude`] or [`Goertzel::power`]. Call [`Goertzel::reset`]
+/// before starting a new measurement.
+///
+/// For a sine wave with amplitude `A` at the target frequency, the magnitude
+/// after `N` samples is approximately `A * N / 2`.
+#[derive(Debug, Clone)]
+pub struct Goertzel {
+    /// Coefficient `2 * cos(w)`.
+    coeff: f32,
+
+    /// Sample memory.
+    states: [f32; 2],
+}
+
+impl Goertzel {
+    /// Returns a new instance tuned to `target_freq` in Hz.
+    ///
+    /// The coefficient is calculated in double precision, because errors
+    /// in the cosine move the detected frequency away from the target.
+    ///
+    /// `sample_time` is `1.0 / sample_rate`.
+    pub fn new(target_freq: f32, sample_time: f32) -> Self {
+        let [_, cos, _, _] = unit_circle(target_freq, sample_time);
+
+        Self {
+            coeff: (2.0 * cos) as f32,
+            states: [0.0; 2],
+        }
+    }
+
+    /// Clears the accumulated state to start a new measurement.
+    pub fn reset(&mut self) {
+        self.states = [0.0; 2];
+    }
+
+    /// Accumulates a single sample.
+    pub fn process_sample(&mut self, sample: f32) {
+        let s = sample + self.coeff * self.states[0] - self.states[1];
+        self.states[1] = self.states[0];
+        self.states[0] = s;
+    }
+
+    /// Accumulates a block of samples.
+    pub fn process_block(&mut self, samples: &[f32]) {
+        for sample in samples {
+            self.process_sample(*sample);
+        }
+    }
+
+    /// Returns the squared magnitude of the target frequency bin.
+    pub fn power(&self) -> f32 {
+        let [s1, s2] = self.states;
+        (s1 * s1 + s2 * s2 - self.coeff * s1 * s2).max(0.0)
+    }
+
+    /// Returns the magnitude of the target frequency bin.
+    pub fn magnitude(&self) -> f32 {
+        math::sqrt(self.power())
+    }
+}
@@ -1,6 +1,40 @@
 #![doc = include_str!("../README.md")]
 #![cfg_attr(not(test), no_std)]
 
+mod analog;
+mod cascade;
+mod dynamic;
+mod goertzel;
+mod math;
+mod modulation;
+mod phaser;
+mod pole_zero;
+mod resonator;
+mod response;
+mod saturation;
+mod stereo;
+mod tilt;
+#[cfg(feature = "std")]
+mod wav;
+
+pub mod test_vectors;
+
+pub use cascade::{
+    halfband_sections, has_first_order_section, sections_for_order, Cascade, HALFBAND_SECTIONS,
+};
+pub use dynamic::DynamicFilter;
+pub use goertzel::Goertzel;
+pub use modulation::{modulation_alias_risk, ModulatedFilter, MODULATION_OVERSAMPLING};
+pub use phaser::{Phaser, PhaserStage};
+pub use pole_zero::{Complex, PoleZeroPlot};
+pub use resonator::resonator_bank_from_notes;
+pub use response::{
+    are_complementary, chain_response_at, integer_delay_to_align, summed_response_db,
+};
+pub use saturation::{SaturatingFilter, Saturation};
+pub use stereo::LinkedStereoBiquad;
+pub use tilt::TiltEstimator;
+
 use core::f32::consts::PI;
 
 use micromath::F32Ext;
@@ -8,6 +42,19 @@ use micromath::F32Ext;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Highest frequency relative to the sample rate used for derived cutoffs.
+const MAX_FREQ_RATIO: f32 = 0.49;
+
+/// Number of samples to crossfade over after a discontinuity.
+const DISCONTINUITY_FADE_LEN: usize = 32;
+
+/// Argument below which `tan` is calculated by its series expansion.
+///
+/// Up to this value, `x + x^3 / 3` is more accurate than the micromath
+/// implementation, whose relative error rises to about 1.3% for small
+/// arguments.
+const SMALL_ANGLE_LIMIT: f32 = 0.35;
+
 /// Filter types.
 #[derive(Debug, Default, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -132,6 +179,103 @@ pub enum FilterType {
         /// Cutoff frequency in Hz.
         freq: f32,
     },
+
+    /// Polarity inversion.
+    Invert,
+}
+
+impl FilterType {
+    /// Returns a peaking EQ with `gain` as linear factor.
+    ///
+    /// `gain` must be greater than 0.0.
+    pub fn peaking_eq_linear(freq: f32, q: f32, gain: f32) -> Self {
+        FilterType::PeakingEq {
+            freq,
+            q,
+            gain: linear_to_db(gain),
+        }
+    }
+
+    /// Returns a low-shelf with `gain` as linear factor.
+    ///
+    /// `gain` must be greater than 0.0.
+    pub fn low_shelf_linear(freq: f32, gain: f32) -> Self {
+        FilterType::LowShelf {
+            freq,
+            gain: linear_to_db(gain),
+        }
+    }
+
+    /// Returns a high-shelf with `gain` as linear factor.
+    ///
+    /// `gain` must be greater than 0.0.
+    pub fn high_shelf_linear(freq: f32, gain: f32) -> Self {
+        FilterType::HighShelf {
+            freq,
+            gain: linear_to_db(gain),
+        }
+    }
+
+    /// Returns a 1st order low-shelf with `gain` as linear factor.
+    ///
+    /// `gain` must be greater than 0.0.
+    pub fn first_order_low_shelf_linear(freq: f32, gain: f32) -> Self {
+        FilterType::FirstOrderLowShelf {
+            freq,
+            gain: linear_to_db(gain),
+        }
+    }
+
+    /// Returns a 1st order high-shelf with `gain` as linear factor.
+    ///
+    /// `gain` must be greater than 0.0.
+    pub fn first_order_high_shelf_linear(freq: f32, gain: f32) -> Self {
+        FilterType::FirstOrderHighShelf {
+            freq,
+            gain: linear_to_db(gain),
+        }
+    }
+
+    /// Returns a first-order low-pass with a 10-90% rise time of
+    /// `rise_time` seconds for a step input.
+    ///
+    /// The cutoff frequency is derived from the time constant
+    /// `tau = rise_time / 2.2` as `1 / (2 * PI * tau)` and limited to just
+    /// below the Nyquist frequency.
+    ///
+    /// `sample_time` is `1.0 / sample_rate`.
+    pub fn first_order_lowpass_for_rise_time(rise_time: f32, sample_time: f32) -> Self {
+        let tau = rise_time / 2.2;
+        let freq = 1.0 / (2.0 * PI * tau);
+        FilterType::FirstOrderLowPass {
+            freq: freq.min(MAX_FREQ_RATIO / sample_time),
+        }
+    }
+
+    /// Returns if the filter type has a gain parameter.
+    pub fn has_gain(&self) -> bool {
+        matches!(
+            self,
+            FilterType::PeakingEq { .. }
+                | FilterType::LowShelf { .. }
+                | FilterType::HighShelf { .. }
+                | FilterType::FirstOrderLowShelf { .. }
+                | FilterType::FirstOrderHighShelf { .. }
+        )
+    }
+
+    /// Returns if the filter type has a Q parameter.
+    pub fn has_q(&self) -> bool {
+        matches!(
+            self,
+            FilterType::LowPass { .. }
+                | FilterType::HighPass { .. }
+                | FilterType::BandPass { .. }
+                | FilterType::Notch { .. }
+                | FilterType::PeakingEq { .. }
+                | FilterType::AllPass { .. }
+        )
+    }
 }
 
 /// Normalized filter coefficients.
@@ -156,25 +300,71 @@ pub struct FilterCoefficients {
 
 impl Default for FilterCoefficients {
     fn default() -> Self {
-        Self {
-            a0: 1.0,
-            a1: 0.0,
-            a2: 0.0,
-            b1: 0.0,
-            b2: 0.0,
-        }
+        Self::bypass()
     }
 }
 
 impl FilterCoefficients {
+    /// Returns coefficients from raw values.
+    ///
+    /// All values must already be normalized, i.e. divided by b0.
+    /// Being a `const fn`, this allows precalculated coefficients to be
+    /// stored in constants without any calculation at runtime.
+    pub const fn from_raw(a0: f32, a1: f32, a2: f32, b1: f32, b2: f32) -> Self {
+        Self { a0, a1, a2, b1, b2 }
+    }
+
+    /// Returns the coefficients for bypass mode.
+    pub const fn bypass() -> Self {
+        Self::from_raw(1.0, 0.0, 0.0, 0.0, 0.0)
+    }
+
     /// Calculates the coefficients from the filter type.
     ///
     /// `sample_time` is `1.0 / sample_rate`.
     pub fn from_type(filter_type: FilterType, sample_time: f32) -> FilterCoefficients {
+        Self::calculate(filter_type, sample_time, None)
+    }
+
+    /// Calculates the coefficients from the filter type, with the bilinear
+    /// transform prewarped at `prewarp_freq` in Hz instead of the frequency
+    /// of the filter type.
+    ///
+    /// The digital response matches the analog prototype exactly at
+    /// `prewarp_freq`. Towards the Nyquist frequency, the digital response is
+    /// increasingly compressed in frequency, so the further the filter
+    /// frequency is away from `prewarp_freq`, the more it deviates from the
+    /// analog one. For example, prewarping a band-pass at one of its edges
+    /// places that edge exactly, at the cost of a slightly shifted center.
+    ///
+    /// The one-pole low-pass is not designed via the bilinear transform and
+    /// therefore not affected.
+    ///
+    /// `sample_time` is `1.0 / sample_rate`.
+    pub fn from_type_prewarp_at(
+        filter_type: FilterType,
+        sample_time: f32,
+        prewarp_freq: f32,
+    ) -> FilterCoefficients {
+        Self::calculate(filter_type, sample_time, Some(prewarp_freq))
+    }
+
+    /// Calculates the coefficients from the filter type, optionally
+    /// prewarping at a frequency other than the one of the filter type.
+    fn calculate(
+        filter_type: FilterType,
+        sample_time: f32,
+        prewarp_freq: Option<f32>,
+    ) -> FilterCoefficients {
+        let warp = |freq: f32| match prewarp_freq {
+            Some(prewarp_freq) => freq / prewarp_freq * prewarp(prewarp_freq, sample_time),
+            None => prewarp(freq, sample_time),
+        };
+
         match filter_type {
             FilterType::Bypass => FilterCoefficients::default(),
             FilterType::LowPass { freq, q } => {
-                let k = (PI * freq * sample_time).tan();
+                let k = warp(freq);
                 let norm = 1.0 / (1.0 + k / q + k * k);
                 let a0 = k * k * norm;
                 Self {
@@ -186,7 +376,7 @@ impl FilterCoefficients {
                 }
             }
             FilterType::HighPass { freq, q } => {
-                let k = (PI * freq * sample_time).tan();
+                let k = warp(freq);
                 let norm = 1.0 / (1.0 + k / q + k * k);
                 let a0 = norm;
                 Self {
@@ -198,7 +388,7 @@ impl FilterCoefficients {
                 }
             }
             FilterType::BandPass { freq, q } => {
-                let k = (PI * freq * sample_time).tan();
+                let k = warp(freq);
                 let norm = 1.0 / (1.0 + k / q + k * k);
                 let a0 = k / q * norm;
                 Self {
@@ -210,7 +400,7 @@ impl FilterCoefficients {
                 }
             }
             FilterType::Notch { freq, q } => {
-                let k = (PI * freq * sample_time).tan();
+                let k = warp(freq);
                 let norm = 1.0 / (1.0 + k / q + k * k);
                 let a0 = (1.0 + k * k) * norm;
                 let a1 = 2.0 * (k * k - 1.0) * norm;
@@ -223,8 +413,8 @@ impl FilterCoefficients {
                 }
             }
             FilterType::PeakingEq { freq, q, gain } => {
-                let k = (PI * freq * sample_time).tan();
-                let v = 10.0.powf(gain.abs() / 20.0);
+                let k = warp(freq);
+                let v = F32Ext::powf(10.0, gain.abs() / 20.0);
                 if gain >= 0.0 {
                     let norm = 1.0 / (1.0 + 1.0 / q * k + k * k);
                     let a1 = 2.0 * (k * k - 1.0) * norm;
@@ -248,53 +438,53 @@ impl FilterCoefficients {
                 }
             }
             FilterType::LowShelf { freq, gain } => {
-                let k = (PI * freq * sample_time).tan();
-                let v = 10.0.powf(gain.abs() / 20.0);
+                let k = warp(freq);
+                let v = F32Ext::powf(10.0, gain.abs() / 20.0);
                 if gain >= 0.0 {
-                    let norm = 1.0 / (1.0 + 2.0.sqrt() * k + k * k);
+                    let norm = 1.0 / (1.0 + F32Ext::sqrt(2.0) * k + k * k);
                     Self {
-                        a0: (1.0 + (2.0 * v).sqrt() * k + v * k * k) * norm,
+                        a0: (1.0 + F32Ext::sqrt(2.0 * v) * k + v * k * k) * norm,
                         a1: 2.0 * (v * k * k - 1.0) * norm,
-                        a2: (1.0 - (2.0 * v).sqrt() * k + v * k * k) * norm,
+                        a2: (1.0 - F32Ext::sqrt(2.0 * v) * k + v * k * k) * norm,
                         b1: 2.0 * (k * k - 1.0) * norm,
-                        b2: (1.0 - 2.0.sqrt() * k + k * k) * norm,
+                        b2: (1.0 - F32Ext::sqrt(2.0) * k + k * k) * norm,
                     }
                 } else {
-                    let norm = 1.0 / (1.0 + (2.0 * v).sqrt() * k + v * k * k);
+                    let norm = 1.0 / (1.0 + F32Ext::sqrt(2.0 * v) * k + v * k * k);
                     Self {
-                        a0: (1.0 + 2.0.sqrt() * k + k * k) * norm,
+                        a0: (1.0 + F32Ext::sqrt(2.0) * k + k * k) * norm,
                         a1: 2.0 * (k * k - 1.0) * norm,
-                        a2: (1.0 - 2.0.sqrt() * k + k * k) * norm,
+                        a2: (1.0 - F32Ext::sqrt(2.0) * k + k * k) * norm,
                         b1: 2.0 * (v * k * k - 1.0) * norm,
-                        b2: (1.0 - (2.0 * v).sqrt() * k + v * k * k) * norm,
+                        b2: (1.0 - F32Ext::sqrt(2.0 * v) * k + v * k * k) * norm,
                     }
                 }
             }
             FilterType::HighShelf { freq, gain } => {
-                let k = (PI * freq * sample_time).tan();
-                let v = 10.0.powf(gain.abs() / 20.0);
+                let k = warp(freq);
+                let v = F32Ext::powf(10.0, gain.abs() / 20.0);
                 if gain >= 0.0 {
-                    let norm = 1.0 / (1.0 + 2.0.sqrt() * k + k * k);
+                    let norm = 1.0 / (1.0 + F32Ext::sqrt(2.0) * k + k * k);
                     Self {
-                        a0: (v + (2.0 * v).sqrt() * k + k * k) * norm,
+                        a0: (v + F32Ext::sqrt(2.0 * v) * k + k * k) * norm,
                         a1: 2.0 * (k * k - v) * norm,
-                        a2: (v - (2.0 * v).sqrt() * k + k * k) * norm,
+                        a2: (v - F32Ext::sqrt(2.0 * v) * k + k * k) * norm,
                         b1: 2.0 * (k * k - 1.0) * norm,
-                        b2: (1.0 - 2.0.sqrt() * k + k * k) * norm,
+                        b2: (1.0 - F32Ext::sqrt(2.0) * k + k * k) * norm,
                     }
                 } else {
-                    let norm = 1.0 / (v + (2.0 * v).sqrt() * k + k * k);
+                    let norm = 1.0 / (v + F32Ext::sqrt(2.0 * v) * k + k * k);
                     Self {
-                        a0: (1.0 + 2.0.sqrt() * k + k * k) * norm,
+                        a0: (1.0 + F32Ext::sqrt(2.0) * k + k * k) * norm,
                         a1: 2.0 * (k * k - 1.0) * norm,
-                        a2: (1.0 - 2.0.sqrt() * k + k * k) * norm,
+                        a2: (1.0 - F32Ext::sqrt(2.0) * k + k * k) * norm,
                         b1: 2.0 * (k * k - v) * norm,
-                        b2: (v - (2.0 * v).sqrt() * k + k * k) * norm,
+                        b2: (v - F32Ext::sqrt(2.0 * v) * k + k * k) * norm,
                     }
                 }
             }
             FilterType::AllPass { freq, q } => {
-                let k = (PI * freq * sample_time).tan();
+                let k = warp(freq);
                 let div_q = 1.0 / q;
                 let norm = 1.0 / (1.0 + k * div_q + k * k);
                 let a0 = (1.0 - k * div_q + k * k) * norm;
@@ -308,7 +498,7 @@ impl FilterCoefficients {
                 }
             }
             FilterType::FirstOrderLowPass { freq } => {
-                let k = (PI * freq * sample_time).tan();
+                let k = warp(freq);
                 let norm = 1.0 / (1.0 / k + 1.0);
                 Self {
                     a0: norm,
@@ -319,7 +509,7 @@ impl FilterCoefficients {
                 }
             }
             FilterType::FirstOrderHighPass { freq } => {
-                let k = (PI * freq * sample_time).tan();
+                let k = warp(freq);
                 let norm = 1.0 / (k + 1.0);
                 Self {
                     a0: norm,
@@ -330,8 +520,8 @@ impl FilterCoefficients {
                 }
             }
             FilterType::FirstOrderLowShelf { freq, gain } => {
-                let k = (PI * freq * sample_time).tan();
-                let v = 10.0.powf(gain.abs() / 20.0);
+                let k = warp(freq);
+                let v = F32Ext::powf(10.0, gain.abs() / 20.0);
                 if gain >= 0.0 {
                     let norm = 1.0 / (k + 1.0);
                     Self {
@@ -353,8 +543,8 @@ impl FilterCoefficients {
                 }
             }
             FilterType::FirstOrderHighShelf { freq, gain } => {
-                let k = (PI * freq * sample_time).tan();
-                let v = 10.0.powf(gain.abs() / 20.0);
+                let k = warp(freq);
+                let v = F32Ext::powf(10.0, gain.abs() / 20.0);
                 if gain >= 0.0 {
                     let norm = 1.0 / (k + 1.0);
                     Self {
@@ -376,7 +566,7 @@ impl FilterCoefficients {
                 }
             }
             FilterType::FirstOrderAllPass { freq } => {
-                let k = (PI * freq * sample_time).tan();
+                let k = warp(freq);
                 let a0 = (1.0 - k) / (1.0 + k);
                 Self {
                     a0,
@@ -387,7 +577,7 @@ impl FilterCoefficients {
                 }
             }
             FilterType::OnePoleLowPass { freq } => {
-                let b1 = (-2.0 * PI * freq * sample_time).exp();
+                let b1 = F32Ext::exp(-2.0 * PI * freq * sample_time);
                 Self {
                     a0: 1.0 - b1,
                     a1: 0.0,
@@ -396,8 +586,100 @@ impl FilterCoefficients {
                     b2: 0.0,
                 }
             }
+            FilterType::Invert => Self::from_raw(-1.0, 0.0, 0.0, 0.0, 0.0),
         }
     }
+
+    /// Returns the coefficients `[a0, a1, a2, b1, b2]` rounded to `decimals`
+    /// decimal places.
+    ///
+    /// This is meant for display only. The rounded values must not be used
+    /// for processing, because small deviations in the coefficients can
+    /// change the response considerably or even make the filter unstable.
+    pub fn rounded(&self, decimals: u32) -> [f32; 5] {
+        let factor = (0..decimals).fold(1.0, |factor, _| factor * 10.0);
+
+        [self.a0, self.a1, self.a2, self.b1, self.b2].map(|value| {
+            let scaled = value * factor;
+            // Beyond 2^23, all f32 values are integers already.
+            if scaled.abs() < 8_388_608.0 {
+                F32Ext::round(scaled) / factor
+            } else {
+                value
+            }
+        })
+    }
+
+    /// Returns the linear interpolation between `self` at `t = 0.0` and
+    /// `other` at `t = 1.0`.
+    pub(crate) fn lerp(&self, other: &FilterCoefficients, t: f32) -> FilterCoefficients {
+        Self {
+            a0: self.a0 + (other.a0 - self.a0) * t,
+            a1: self.a1 + (other.a1 - self.a1) * t,
+            a2: self.a2 + (other.a2 - self.a2) * t,
+            b1: self.b1 + (other.b1 - self.b1) * t,
+            b2: self.b2 + (other.b2 - self.b2) * t,
+        }
+    }
+
+    /// Writes the first `out.len()` samples of the impulse response to `out`.
+    ///
+    /// The response is calculated directly from the difference equation,
+    /// without the state handling of a filter structure.
+    pub fn impulse_taps(&self, out: &mut [f32]) {
+        let mut prev = [0.0; 2];
+
+        for (n, tap) in out.iter_mut().enumerate() {
+            let input = match n {
+                0 => self.a0,
+                1 => self.a1,
+                2 => self.a2,
+                _ => 0.0,
+            };
+            *tap = input - self.b1 * prev[0] - self.b2 * prev[1];
+            prev = [*tap, prev[0]];
+        }
+    }
+}
+
+/// Converts a linear gain factor to dB.
+///
+/// The filter types store their gain in dB, so it is serialized the same
+/// way regardless of how it was specified.
+fn linear_to_db(gain: f32) -> f32 {
+    debug_assert!(gain > 0.0, "linear gain must be greater than 0.0");
+    20.0 * math::log10(gain)
+}
+
+/// Returns the automation gain at sample `index`.
+///
+/// `next` is the index of the first breakpoint after the previous call and
+/// is advanced accordingly, so consecutive indices are processed without
+/// searching.
+fn automation_gain(breakpoints: &[(usize, f32)], index: usize, next: &mut usize) -> f32 {
+    while *next < breakpoints.len() && breakpoints[*next].0 <= index {
+        *next += 1;
+    }
+
+    let prev = next.checked_sub(1).map(|prev| breakpoints[prev]);
+    match (prev, breakpoints.get(*next)) {
+        (Some((start, from)), Some(&(end, to))) => {
+            from + (to - from) * (index - start) as f32 / (end - start) as f32
+        }
+        (Some((_, gain)), None) | (None, Some(&(_, gain))) => gain,
+        (None, None) => 1.0,
+    }
+}
+
+/// Returns the prewarped frequency `k = tan(PI * freq * sample_time)` used
+/// by the bilinear transform.
+fn prewarp(freq: f32, sample_time: f32) -> f32 {
+    let x = PI * freq * sample_time;
+    if x < SMALL_ANGLE_LIMIT {
+        x + x * x * x / 3.0
+    } else {
+        F32Ext::tan(x)
+    }
 }
 
 /// Direct form 1.
@@ -411,6 +693,12 @@ pub struct DirectForm1 {
 
     /// Output sample memory.
     out_states: [f32; 2],
+
+    /// Input jump between blocks that triggers a crossfade.
+    discontinuity_threshold: Option<f32>,
+
+    /// Last input sample of the previous block.
+    last_input: Option<f32>,
 }
 
 impl DirectForm1 {
@@ -429,6 +717,33 @@ impl DirectForm1 {
         self.coeffs = coeffs;
     }
 
+    /// Returns the -3 dB cutoff frequency in Hz of the current coefficients.
+    ///
+    /// See [`FilterCoefficients::cutoff_3db`] for details.
+    ///
+    /// `sample_time` is `1.0 / sample_rate`.
+    pub fn effective_cutoff(&self, sample_time: f32) -> Option<f32> {
+        self.coeffs.cutoff_3db(sample_time)
+    }
+
+    /// Sets the threshold for discontinuity handling in
+    /// [`DirectForm1::process_block`].
+    ///
+    /// If the first sample of a block differs from the last sample of the
+    /// previous block by more than `threshold`, e.g. because a block was
+    /// dropped, the state is adjusted as if the input had been continuous.
+    /// The output is crossfaded from the original to the adjusted state over
+    /// a short period to avoid the ringing caused by the jump. Blocks shorter
+    /// than this period are crossfaded over their whole length.
+    ///
+    /// The first block after creating the filter is never treated as a
+    /// discontinuity, as there is no previous block to compare with.
+    ///
+    /// `None` disables the discontinuity handling, which is the default.
+    pub fn set_discontinuity_threshold(&mut self, threshold: Option<f32>) {
+        self.discontinuity_threshold = threshold;
+    }
+
     /// Processes a single sample.
     pub fn process_sample(&mut self, sample: f32) -> f32 {
         let out_sample = self.coeffs.a0 * sample
@@ -447,10 +762,113 @@ impl DirectForm1 {
     }
 
     /// Processes a block of samples in-place.
+    ///
+    /// See [`DirectForm1::set_discontinuity_threshold`] for the handling
+    /// of jumps between blocks.
     pub fn process_block(&mut self, samples: &mut [f32]) {
+        if samples.is_empty() {
+            return;
+        }
+
+        let mut samples = samples;
+
+        if let (Some(threshold), Some(last), Some(first)) = (
+            self.discontinuity_threshold,
+            self.last_input,
+            samples.first(),
+        ) {
+            let jump = first - last;
+            if jump.abs() > threshold {
+                let (fade, rest) = samples.split_at_mut(DISCONTINUITY_FADE_LEN.min(samples.len()));
+                self.process_discontinuity(fade, jump);
+                samples = rest;
+            }
+        }
+
         for sample in samples.iter_mut() {
             *sample = self.process_sample(*sample);
         }
+
+        self.last_input = Some(self.in_states[0]);
+    }
+
+    /// Processes a block of samples and adds the result to `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` and `out` differ in length.
+    pub fn process_block_add(&mut self, input: &[f32], out: &mut [f32]) {
+        assert_eq!(input.len(), out.len());
+
+        for (sample, out_sample) in input.iter().zip(out.iter_mut()) {
+            *out_sample += self.process_sample(*sample);
+        }
+    }
+
+    /// Processes a block of samples in-place, applying a gain automation
+    /// to the output.
+    ///
+    /// `breakpoints` are `(sample_index, gain)` pairs sorted by index. The
+    /// gain is interpolated linearly between them and held before the first
+    /// and after the last breakpoint. Without breakpoints, the gain is 1.0.
+    pub fn process_block_automated(&mut self, samples: &mut [f32], breakpoints: &[(usize, f32)]) {
+        let mut next = 0;
+
+        for (idx, sample) in samples.iter_mut().enumerate() {
+            *sample = self.process_sample(*sample) * automation_gain(breakpoints, idx, &mut next);
+        }
+    }
+
+    /// Processes a block of samples in-place, using `coeffs[i]` for
+    /// `samples[i]`.
+    ///
+    /// The sample memory is kept across the coefficient changes, so the
+    /// state evolves continuously while the coefficients change per sample.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples` and `coeffs` differ in length.
+    pub fn process_block_with_coeffs(
+        &mut self,
+        samples: &mut [f32],
+        coeffs: &[FilterCoefficients],
+    ) {
+        assert_eq!(samples.len(), coeffs.len());
+
+        for (sample, coeffs) in samples.iter_mut().zip(coeffs.iter()) {
+            self.set_coefficients(coeffs.clone());
+            *sample = self.process_sample(*sample);
+        }
+    }
+
+    /// Processes `samples` while crossfading from the current state to a
+    /// state shifted by an input jump of `jump`.
+    fn process_discontinuity(&mut self, samples: &mut [f32], jump: f32) {
+        let mut aligned = self.clone();
+        aligned
+            .in_states
+            .iter_mut()
+            .for_each(|state| *state += jump);
+
+        let dc_gain = self.coeffs.dc_gain();
+        if dc_gain.is_finite() {
+            let out_jump = jump * dc_gain;
+            aligned
+                .out_states
+                .iter_mut()
+                .for_each(|state| *state += out_jump);
+        }
+
+        let len = samples.len() as f32;
+        for (n, sample) in samples.iter_mut().enumerate() {
+            let original = self.process_sample(*sample);
+            let adjusted = aligned.process_sample(*sample);
+            let mix = (n + 1) as f32 / len;
+            *sample = original + (adjusted - original) * mix;
+        }
+
+        self.in_states = aligned.in_states;
+        self.out_states = aligned.out_states;
     }
 }
 
@@ -480,6 +898,15 @@ impl DirectForm2Transposed {
         self.coeffs = coeffs;
     }
 
+    /// Returns the -3 dB cutoff frequency in Hz of the current coefficients.
+    ///
+    /// See [`FilterCoefficients::cutoff_3db`] for details.
+    ///
+    /// `sample_time` is `1.0 / sample_rate`.
+    pub fn effective_cutoff(&self, sample_time: f32) -> Option<f32> {
+        self.coeffs.cutoff_3db(sample_time)
+    }
+
     /// Processes a single sample.
     pub fn process_sample(&mut self, sample: f32) -> f32 {
         let out_sample = self.states[0] + self.coeffs.a0 * sample;
@@ -496,4 +923,53 @@ impl DirectForm2Transposed {
             *sample = self.process_sample(*sample);
         }
     }
+
+    /// Processes a block of samples and adds the result to `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` and `out` differ in length.
+    pub fn process_block_add(&mut self, input: &[f32], out: &mut [f32]) {
+        assert_eq!(input.len(), out.len());
+
+        for (sample, out_sample) in input.iter().zip(out.iter_mut()) {
+            *out_sample += self.process_sample(*sample);
+        }
+    }
+
+    /// Processes a block of samples in-place, applying a gain automation
+    /// to the output.
+    ///
+    /// `breakpoints` are `(sample_index, gain)` pairs sorted by index. The
+    /// gain is interpolated linearly between them and held before the first
+    /// and after the last breakpoint. Without breakpoints, the gain is 1.0.
+    pub fn process_block_automated(&mut self, samples: &mut [f32], breakpoints: &[(usize, f32)]) {
+        let mut next = 0;
+
+        for (idx, sample) in samples.iter_mut().enumerate() {
+            *sample = self.process_sample(*sample) * automation_gain(breakpoints, idx, &mut next);
+        }
+    }
+
+    /// Processes a block of samples in-place, using `coeffs[i]` for
+    /// `samples[i]`.
+    ///
+    /// The sample memory is kept across the coefficient changes, so the
+    /// state evolves continuously while the coefficients change per sample.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples` and `coeffs` differ in length.
+    pub fn process_block_with_coeffs(
+        &mut self,
+        samples: &mut [f32],
+        coeffs: &[FilterCoefficients],
+    ) {
+        assert_eq!(samples.len(), coeffs.len());
+
+        for (sample, coeffs) in samples.iter_mut().zip(coeffs.iter()) {
+            self.set_coefficients(coeffs.clone());
+            *sample = self.process_sample(*sample);
+        }
+    }
 }
@@ -0,0 +1,81 @@
+//! Math functions where the precision of micromath is insufficient.
+//!
+//! These are used for analysis, where errors of micromath in the range of
+//! several percent would render the results useless.
+
+use core::f32::consts::{LN_2, LOG10_E, SQRT_2};
+
+use micromath::F32Ext;
+
+/// Returns the sine of `x` for `|x| <= PI / 2` in double precision.
+///
+/// A Taylor series is used, with an error below `4e-6`.
+pub(crate) fn sin_f64(x: f64) -> f64 {
+    let x2 = x * x;
+    x * (1.0
+        - x2 / 6.0 * (1.0 - x2 / 20.0 * (1.0 - x2 / 42.0 * (1.0 - x2 / 72.0 * (1.0 - x2 / 110.0)))))
+}
+
+/// Returns the cosine of `x` for `|x| <= PI / 2` in double precision.
+///
+/// A Taylor series is used, with an error below `1e-6`.
+pub(crate) fn cos_f64(x: f64) -> f64 {
+    let x2 = x * x;
+    1.0 - x2 / 2.0
+        * (1.0
+            - x2 / 12.0
+                * (1.0 - x2 / 30.0 * (1.0 - x2 / 56.0 * (1.0 - x2 / 90.0 * (1.0 - x2 / 132.0)))))
+}
+
+/// Returns the square root of `x`.
+///
+/// The micromath approximation is refined by two Newton iterations.
+pub(crate) fn sqrt(x: f32) -> f32 {
+    if x <= 0.0 || !x.is_finite() {
+        return F32Ext::sqrt(x);
+    }
+
+    let mut y = F32Ext::sqrt(x);
+    y = 0.5 * (y + x / y);
+    0.5 * (y + x / y)
+}
+
+/// Returns the base 10 logarithm of `x`.
+pub(crate) fn log10(x: f32) -> f32 {
+    ln(x) * LOG10_E
+}
+
+/// Returns the natural logarithm of `x`.
+pub(crate) fn ln(x: f32) -> f32 {
+    if x.is_nan() || x < 0.0 {
+        return f32::NAN;
+    }
+    if x == 0.0 {
+        return f32::NEG_INFINITY;
+    }
+    if x.is_infinite() {
+        return x;
+    }
+
+    let bits = x.to_bits();
+    let exponent_bits = (bits >> 23) & 0xff;
+    if exponent_bits == 0 {
+        // Subnormal numbers are scaled into the normal range first.
+        return ln(x * (1u32 << 23) as f32) - 23.0 * LN_2;
+    }
+
+    let mut exponent = exponent_bits as i32 - 127;
+    let mut mantissa = f32::from_bits((bits & 0x007f_ffff) | 0x3f80_0000);
+    if mantissa > SQRT_2 {
+        mantissa /= 2.0;
+        exponent += 1;
+    }
+
+    // ln(m) = 2 * atanh(s) with s = (m - 1) / (m + 1), |s| < 0.18.
+    let s = (mantissa - 1.0) / (mantissa + 1.0);
+    let s2 = s * s;
+    let ln_mantissa =
+        2.0 * s * (1.0 + s2 * (1.0 / 3.0 + s2 * (1.0 / 5.0 + s2 * (1.0 / 7.0 + s2 / 9.0))));
+
+    exponent as f32 * LN_2 + ln_mantissa
+}
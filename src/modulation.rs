@@ -0,0 +1,150 @@
+//! Helpers for time-varying filters.
+
+use core::f32::consts::{LN_2, PI};
+
+use micromath::F32Ext;
+
+use crate::{
+    halfband_sections, math, Cascade, DirectForm2Transposed, FilterCoefficients, FilterType,
+    HALFBAND_SECTIONS,
+};
+
+/// Oversampling factor of [`ModulatedFilter`].
+pub const MODULATION_OVERSAMPLING: usize = 2;
+
+/// Pole frequency change per sample in octaves that results in full risk.
+const FULL_RISK_OCT: f32 = 1.0 / 12.0;
+
+/// Returns a score from 0.0 to 1.0 estimating the risk of artifacts caused
+/// by changing the coefficients too fast.
+///
+/// `coeffs` is the sequence of coefficients applied to consecutive samples.
+/// The natural frequency of the dominant pole is tracked and the largest
+/// change between two samples is compared to a semitone, which results in a
+/// score of 1.0. Changes in Q are not taken into account.
+///
+/// `sample_time` is `1.0 / sample_rate`.
+pub fn modulation_alias_risk(coeffs: &[FilterCoefficients], sample_time: f32) -> f32 {
+    let max_change = coeffs
+        .windows(2)
+        .filter_map(|pair| {
+            let from = pole_frequency(&pair[0], sample_time);
+            let to = pole_frequency(&pair[1], sample_time);
+            (from.is_finite() && to.is_finite() && from > 0.0 && to > 0.0)
+                .then(|| (math::ln(to / from) / LN_2).abs())
+        })
+        .fold(0.0, f32::max);
+
+    (max_change / FULL_RISK_OCT).min(1.0)
+}
+
+/// Returns the natural frequency in Hz of the dominant pole, mapped back to
+/// the s-plane.
+fn pole_frequency(coeffs: &FilterCoefficients, sample_time: f32) -> f32 {
+    let pole = coeffs.poles()[0];
+    let ln_radius = math::ln(pole.norm());
+    let angle = F32Ext::atan2(pole.im, pole.re);
+    math::sqrt(ln_radius * ln_radius + angle * angle) / (2.0 * PI * sample_time)
+}
+
+/// Filter for fast modulation, running at twice the sample rate.
+///
+/// Each block is upsampled, filtered with coefficients interpolated per
+/// sample from the previous to the target filter type and downsampled
+/// again. Both the doubled rate and the per-sample interpolation reduce the
+/// artifacts of fast filter sweeps.
+///
+/// Resampling uses the filter from [`halfband_sections`] in both
+/// directions, so the passband is limited to 40% of the sample rate.
+///
+/// Costs compared to a single biquad:
+///
+/// - CPU: about 20 times the work per sample, for 2 filter and 16 resampling
+///   section evaluations plus 2 coefficient interpolations.
+/// - Latency: about 3.5 samples at low frequencies, 4.5 samples at 20% and
+///   7 samples at 30% of the sample rate, rising steeply to about 28 samples
+///   at the upper end of the passband.
+#[derive(Debug, Clone)]
+pub struct ModulatedFilter {
+    /// Filter running at the oversampled rate.
+    filter: DirectForm2Transposed,
+
+    /// Anti-imaging filter for upsampling.
+    upsampler: Cascade<HALFBAND_SECTIONS>,
+
+    /// Anti-aliasing filter for downsampling.
+    downsampler: Cascade<HALFBAND_SECTIONS>,
+
+    /// Sample time in seconds at the oversampled rate.
+    sample_time: f32,
+
+    /// Coefficients at the start of the next block.
+    current: FilterCoefficients,
+
+    /// Coefficients at the end of the next block.
+    target: FilterCoefficients,
+}
+
+impl ModulatedFilter {
+    /// Returns a new instance in bypass mode.
+    ///
+    /// `sample_time` is `1.0 / sample_rate` of the signal to be processed.
+    pub fn new(sample_time: f32) -> Self {
+        let mut upsampler = Cascade::new();
+        let mut downsampler = Cascade::new();
+
+        for (idx, section) in halfband_sections().into_iter().enumerate() {
+            upsampler.set_coefficients(idx, section.clone());
+            downsampler.set_coefficients(idx, section);
+        }
+
+        Self {
+            filter: DirectForm2Transposed::new(),
+            upsampler,
+            downsampler,
+            sample_time: sample_time / MODULATION_OVERSAMPLING as f32,
+            current: FilterCoefficients::default(),
+            target: FilterCoefficients::default(),
+        }
+    }
+
+    /// Resets the filter to bypass mode immediately.
+    pub fn reset(&mut self) {
+        self.current = FilterCoefficients::default();
+        self.target = FilterCoefficients::default();
+    }
+
+    /// Sets the filter type reached at the end of the next processed block.
+    pub fn set_target_type(&mut self, filter_type: FilterType) {
+        self.target = FilterCoefficients::from_type(filter_type, self.sample_time);
+    }
+
+    /// Processes a block of samples in-place.
+    ///
+    /// The coefficients are interpolated across the block from the previous
+    /// to the target filter type.
+    pub fn process_block(&mut self, samples: &mut [f32]) {
+        let steps = (samples.len() * MODULATION_OVERSAMPLING) as f32;
+
+        for (idx, sample) in samples.iter_mut().enumerate() {
+            for phase in 0..MODULATION_OVERSAMPLING {
+                // Zero stuffing, compensating the gain loss.
+                let input = if phase == 0 {
+                    *sample * MODULATION_OVERSAMPLING as f32
+                } else {
+                    0.0
+                };
+
+                let step = idx * MODULATION_OVERSAMPLING + phase + 1;
+                self.filter
+                    .set_coefficients(self.current.lerp(&self.target, step as f32 / steps));
+
+                let upsampled = self.upsampler.process_sample(input);
+                let filtered = self.filter.process_sample(upsampled);
+                *sample = self.downsampler.process_sample(filtered);
+            }
+        }
+
+        self.current = self.target.clone();
+    }
+}
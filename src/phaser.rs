@@ -0,0 +1,141 @@
+//! Phaser built from first-order all-pass stages.
+
+use micromath::F32Ext;
+
+use crate::{DirectForm2Transposed, FilterCoefficients, FilterType, MAX_FREQ_RATIO};
+
+/// Initial center frequency of a phaser in Hz.
+const DEFAULT_CENTER: f32 = 1000.0;
+
+/// Maximum magnitude of the feedback amount.
+const MAX_FEEDBACK: f32 = 0.95;
+
+/// Single first-order all-pass stage of a phaser.
+#[derive(Debug, Default, Clone)]
+pub struct PhaserStage {
+    /// All-pass filter.
+    filter: DirectForm2Transposed,
+}
+
+impl PhaserStage {
+    /// Returns a new instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the frequency at which the stage has a phase shift of 90°.
+    ///
+    /// The frequency is limited to just below the Nyquist frequency, because
+    /// the all-pass becomes unstable beyond it.
+    ///
+    /// `sample_time` is `1.0 / sample_rate`.
+    pub fn set_freq(&mut self, freq: f32, sample_time: f32) {
+        self.filter.set_coefficients(FilterCoefficients::from_type(
+            FilterType::FirstOrderAllPass {
+                freq: freq.min(MAX_FREQ_RATIO / sample_time),
+            },
+            sample_time,
+        ));
+    }
+
+    /// Processes a single sample.
+    pub fn process_sample(&mut self, sample: f32) -> f32 {
+        self.filter.process_sample(sample)
+    }
+}
+
+/// Phaser with `N` all-pass stages.
+///
+/// The output is the sum of the dry signal and the all-pass chain, which
+/// creates `N / 2` notches in the spectrum. Sweeping the center frequency,
+/// e.g. by an LFO, moves all notches together.
+#[derive(Debug, Clone)]
+pub struct Phaser<const N: usize> {
+    /// All-pass stages.
+    stages: [PhaserStage; N],
+
+    /// Sample time in seconds.
+    sample_time: f32,
+
+    /// Center frequency in Hz.
+    center: f32,
+
+    /// Frequency ratio between adjacent stages.
+    spread: f32,
+
+    /// Amount of the all-pass output fed back to the input.
+    feedback: f32,
+
+    /// Last output of the all-pass chain.
+    feedback_sample: f32,
+}
+
+impl<const N: usize> Phaser<N> {
+    /// Returns a new instance.
+    ///
+    /// `sample_time` is `1.0 / sample_rate`.
+    pub fn new(sample_time: f32) -> Self {
+        let mut phaser = Self {
+            stages: core::array::from_fn(|_| PhaserStage::new()),
+            sample_time,
+            center: DEFAULT_CENTER,
+            spread: 1.0,
+            feedback: 0.0,
+            feedback_sample: 0.0,
+        };
+        phaser.update_stages();
+        phaser
+    }
+
+    /// Sets the center frequency of the stages in Hz.
+    pub fn set_center(&mut self, freq: f32) {
+        self.center = freq;
+        self.update_stages();
+    }
+
+    /// Sets the frequency ratio between adjacent stages.
+    ///
+    /// The stages are spread symmetrically around the center frequency.
+    /// A value of `1.0` tunes all stages to the center frequency.
+    pub fn set_spread(&mut self, spread: f32) {
+        self.spread = spread;
+        self.update_stages();
+    }
+
+    /// Sets the feedback amount.
+    ///
+    /// Negative values invert the polarity of the feedback. The value is
+    /// clamped to ±0.95 to keep the feedback loop stable.
+    pub fn set_feedback(&mut self, feedback: f32) {
+        self.feedback = feedback.clamp(-MAX_FEEDBACK, MAX_FEEDBACK);
+    }
+
+    /// Processes a single sample.
+    pub fn process_sample(&mut self, sample: f32) -> f32 {
+        let input = sample + self.feedback * self.feedback_sample;
+        let wet = self
+            .stages
+            .iter_mut()
+            .fold(input, |sample, stage| stage.process_sample(sample));
+        self.feedback_sample = wet;
+
+        0.5 * (sample + wet)
+    }
+
+    /// Processes a block of samples in-place.
+    pub fn process_block(&mut self, samples: &mut [f32]) {
+        for sample in samples.iter_mut() {
+            *sample = self.process_sample(*sample);
+        }
+    }
+
+    /// Sets the frequencies of all stages from center and spread.
+    fn update_stages(&mut self) {
+        let offset = (N as f32 - 1.0) / 2.0;
+
+        for (idx, stage) in self.stages.iter_mut().enumerate() {
+            let freq = self.center * F32Ext::powf(self.spread, idx as f32 - offset);
+            stage.set_freq(freq, self.sample_time);
+        }
+    }
+}
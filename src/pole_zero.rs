@@ -0,0 +1,131 @@
+//! Poles and zeros of the transfer function.
+
+use core::ops::Mul;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{math, FilterCoefficients};
+
+/// Complex number.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Complex {
+    /// Real part.
+    pub re: f32,
+
+    /// Imaginary part.
+    pub im: f32,
+}
+
+impl Complex {
+    /// Returns a new instance.
+    pub fn new(re: f32, im: f32) -> Self {
+        Self { re, im }
+    }
+
+    /// Returns the distance from the origin.
+    pub fn norm(&self) -> f32 {
+        if self.im == 0.0 {
+            self.re.abs()
+        } else {
+            math::sqrt(self.re * self.re + self.im * self.im)
+        }
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, rhs: Complex) -> Complex {
+        Complex::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+/// Data for drawing a pole-zero plot.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PoleZeroPlot {
+    /// Poles.
+    pub poles: [Complex; 2],
+
+    /// Zeros.
+    pub zeros: [Complex; 2],
+
+    /// Distances of the poles from the origin.
+    pub pole_radii: [f32; 2],
+
+    /// Distances of the zeros from the origin.
+    pub zero_radii: [f32; 2],
+
+    /// Whether the filter is stable.
+    pub stable: bool,
+}
+
+impl FilterCoefficients {
+    /// Returns the poles.
+    ///
+    /// Complex poles are returned with the positive imaginary part first,
+    /// real poles with the larger magnitude first.
+    pub fn poles(&self) -> [Complex; 2] {
+        quadratic_roots(self.b1, self.b2)
+    }
+
+    /// Returns the zeros.
+    ///
+    /// Complex zeros are returned with the positive imaginary part first,
+    /// real zeros with the larger magnitude first. If the order of the
+    /// numerator is lower than 2, the missing zeros are located at infinity,
+    /// which is represented by an infinite real part.
+    pub fn zeros(&self) -> [Complex; 2] {
+        let infinity = Complex::new(f32::INFINITY, 0.0);
+
+        if self.a0 != 0.0 {
+            quadratic_roots(self.a1 / self.a0, self.a2 / self.a0)
+        } else if self.a1 != 0.0 {
+            [Complex::new(-self.a2 / self.a1, 0.0), infinity]
+        } else {
+            [infinity, infinity]
+        }
+    }
+
+    /// Returns if the filter is stable, i.e. all poles are inside the
+    /// unit circle.
+    pub fn is_stable(&self) -> bool {
+        self.b2.abs() < 1.0 && self.b1.abs() < 1.0 + self.b2
+    }
+
+    /// Returns all data needed for drawing a pole-zero plot.
+    pub fn plot_data(&self) -> PoleZeroPlot {
+        let poles = self.poles();
+        let zeros = self.zeros();
+
+        PoleZeroPlot {
+            poles,
+            zeros,
+            pole_radii: poles.map(|pole| pole.norm()),
+            zero_radii: zeros.map(|zero| zero.norm()),
+            stable: self.is_stable(),
+        }
+    }
+}
+
+/// Returns the roots of `z^2 + p * z + q`.
+fn quadratic_roots(p: f32, q: f32) -> [Complex; 2] {
+    let center = -p / 2.0;
+    let discriminant = center * center - q;
+
+    if discriminant >= 0.0 {
+        // The root with the larger magnitude is calculated directly, the
+        // other one via their product to avoid cancellation.
+        let larger = center + math::sqrt(discriminant).copysign(center);
+        let smaller = if larger != 0.0 { q / larger } else { 0.0 };
+        [Complex::new(larger, 0.0), Complex::new(smaller, 0.0)]
+    } else {
+        let offset = math::sqrt(-discriminant);
+        [Complex::new(center, offset), Complex::new(center, -offset)]
+    }
+}
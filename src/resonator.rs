@@ -0,0 +1,90 @@
+//! Resonator banks tuned to musical notes.
+
+use core::f32::consts::LN_10;
+use core::f64::consts::SQRT_2;
+
+use micromath::F32Ext;
+
+use crate::response::unit_circle;
+use crate::FilterCoefficients;
+
+/// Frequency ratios of the semitones within an octave in equal temperament.
+const SEMITONE_RATIOS: [f64; 12] = [
+    1.0,
+    1.0594630943592953,
+    1.122462048309373,
+    1.189207115002721,
+    1.2599210498948732,
+    1.3348398541700344,
+    SQRT_2,
+    1.4983070768766815,
+    1.5874010519681994,
+    1.681792830507429,
+    1.7817974362806785,
+    1.8877486253633868,
+];
+
+/// Fills `out` with the coefficients of one resonator per MIDI note in `notes`.
+///
+/// Each resonator is a two-pole filter with unity gain at the note frequency
+/// and an impulse response decaying by 60 dB within `decay_seconds`. Notes use
+/// equal temperament with A4 (note 69) at 440 Hz.
+///
+/// For notes in the lowest octaves, the peak deviates from the note
+/// frequency, because the resonance can be wider than the distance to DC and
+/// the coefficients lack precision for narrow resonances at low frequencies.
+///
+/// The resonators are meant to be run in parallel, e.g. by processing the
+/// same input with each of them via `process_block_add` into a shared output.
+///
+/// `sample_time` is `1.0 / sample_rate`.
+///
+/// # Panics
+///
+/// Panics if `notes` and `out` differ in length.
+pub fn resonator_bank_from_notes(
+    notes: &[u8],
+    decay_seconds: f32,
+    sample_time: f32,
+    out: &mut [FilterCoefficients],
+) {
+    assert_eq!(notes.len(), out.len());
+
+    // Pole radius for a decay of 60 dB, i.e. a factor of 1000.
+    let r = F32Ext::exp(-3.0 * LN_10 * sample_time / decay_seconds);
+    let gain = (1.0 - r * r) / 2.0;
+    let r = r as f64;
+
+    for (note, coeffs) in notes.iter().zip(out.iter_mut()) {
+        let [_, cos, _, _] = unit_circle(note_freq(*note), sample_time);
+        // With zeros at DC and Nyquist, the peak is located slightly off the
+        // pole angle. The pole angle is chosen so that the peak, which has
+        // unity gain, is exactly at the note frequency. This is not possible
+        // if the resonance is wider than the distance to DC or Nyquist.
+        let pole_cos = ((1.0 + r * r) / (2.0 * r) * cos).clamp(-1.0, 1.0);
+        *coeffs = FilterCoefficients {
+            a0: gain,
+            a1: 0.0,
+            a2: -gain,
+            b1: (-2.0 * r * pole_cos) as f32,
+            b2: (r * r) as f32,
+        };
+    }
+}
+
+/// Returns the frequency of MIDI note `note` in Hz.
+///
+/// The octave is applied as exact power of 2, so only the semitone ratio is
+/// subject to rounding.
+fn note_freq(note: u8) -> f32 {
+    let offset = note as i32 - 69;
+    let octave = offset.div_euclid(12);
+    let ratio = SEMITONE_RATIOS[offset.rem_euclid(12) as usize];
+    let octave_factor = if octave >= 0 {
+        (1 << octave) as f64
+    } else {
+        1.0 / (1 << -octave) as f64
+    };
+
+    (440.0 * ratio * octave_factor) as f32
+}
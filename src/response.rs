@@ -0,0 +1,360 @@
+//! Frequency response evaluation.
+
+use core::f64::consts::{FRAC_PI_2, PI};
+
+use micromath::F32Ext;
+
+use crate::{math, Complex, FilterCoefficients};
+
+/// Number of points used when scanning the frequency response.
+pub(crate) const SCAN_POINTS: usize = 512;
+
+/// Width of the finite difference used for slope estimation in octaves.
+const SLOPE_STEP_OCT: f32 = 0.1;
+
+/// Number of bisection steps used to refine a frequency between scan points.
+const BISECTION_STEPS: usize = 24;
+
+/// Number of golden-section steps used to refine a local maximum of a scan.
+const GOLDEN_SECTION_STEPS: usize = 40;
+
+/// Lowest squared magnitude of the passband relative to the peak for a
+/// cutoff frequency to be determined, corresponding to -40 dB.
+const MIN_PASSBAND_RATIO: f32 = 1e-4;
+
+impl FilterCoefficients {
+    /// Returns the squared magnitude response at `freq` in Hz.
+    ///
+    /// The response is calculated from `sin^2(w / 2)` instead of evaluating
+    /// the polynomials on the unit circle, which avoids cancellation at low
+    /// frequencies. Double precision is used for the same reason, as the
+    /// coefficients of low-frequency filters almost sum up to zero.
+    ///
+    /// `sample_time` is `1.0 / sample_rate`.
+    pub(crate) fn magnitude_squared_at(&self, freq: f32, sample_time: f32) -> f32 {
+        let x = PI * freq as f64 * sample_time as f64;
+        let s = if x.abs() <= FRAC_PI_2 {
+            math::sin_f64(x)
+        } else {
+            F32Ext::sin(x as f32) as f64
+        };
+        let phi = s * s;
+
+        let poly = |c0: f32, c1: f32, c2: f32| {
+            let (c0, c1, c2) = (c0 as f64, c1 as f64, c2 as f64);
+            let sum = c0 + c1 + c2;
+            (sum * sum - 4.0 * (c0 * c1 + 4.0 * c0 * c2 + c1 * c2) * phi
+                + 16.0 * c0 * c2 * phi * phi)
+                .max(0.0)
+        };
+
+        (poly(self.a0, self.a1, self.a2) / poly(1.0, self.b1, self.b2)) as f32
+    }
+
+    /// Returns the complex frequency response at `freq` in Hz.
+    ///
+    /// `sample_time` is `1.0 / sample_rate`.
+    pub fn response_at(&self, freq: f32, sample_time: f32) -> Complex {
+        let [sin1, cos1, sin2, cos2] = unit_circle(freq, sample_time);
+
+        let eval = |k0: f32, k1: f32, k2: f32| {
+            let (k0, k1, k2) = (k0 as f64, k1 as f64, k2 as f64);
+            (k0 + k1 * cos1 + k2 * cos2, -(k1 * sin1 + k2 * sin2))
+        };
+        let (num_re, num_im) = eval(self.a0, self.a1, self.a2);
+        let (den_re, den_im) = eval(1.0, self.b1, self.b2);
+        let den_norm = den_re * den_re + den_im * den_im;
+
+        Complex::new(
+            ((num_re * den_re + num_im * den_im) / den_norm) as f32,
+            ((num_im * den_re - num_re * den_im) / den_norm) as f32,
+        )
+    }
+
+    /// Returns the group delay at `freq` in Hz in samples.
+    ///
+    /// `sample_time` is `1.0 / sample_rate`.
+    pub fn group_delay_at(&self, freq: f32, sample_time: f32) -> f32 {
+        let [sin1, cos1, sin2, cos2] = unit_circle(freq, sample_time);
+
+        // The group delay of a polynomial P(z) in z^-1 is the real part of
+        // (sum of n * c_n * z^-n) / P(z).
+        let delay = |k0: f32, k1: f32, k2: f32| {
+            let (k0, k1, k2) = (k0 as f64, k1 as f64, k2 as f64);
+            let (p_re, p_im) = (k0 + k1 * cos1 + k2 * cos2, -(k1 * sin1 + k2 * sin2));
+            let (d_re, d_im) = (k1 * cos1 + 2.0 * k2 * cos2, -(k1 * sin1 + 2.0 * k2 * sin2));
+            (d_re * p_re + d_im * p_im) / (p_re * p_re + p_im * p_im)
+        };
+
+        (delay(self.a0, self.a1, self.a2) - delay(1.0, self.b1, self.b2)) as f32
+    }
+
+    /// Returns the gain at 0 Hz.
+    pub(crate) fn dc_gain(&self) -> f32 {
+        (self.a0 + self.a1 + self.a2) / (1.0 + self.b1 + self.b2)
+    }
+
+    /// Returns the magnitude response at `freq` in Hz in dB.
+    ///
+    /// `sample_time` is `1.0 / sample_rate`.
+    pub fn magnitude_db_at(&self, freq: f32, sample_time: f32) -> f32 {
+        10.0 * math::log10(self.magnitude_squared_at(freq, sample_time))
+    }
+
+    /// Returns the highest gain of the magnitude response in dB.
+    ///
+    /// The response is scanned from 0 Hz to the Nyquist frequency and the
+    /// local maxima of the scan are refined, so narrow resonances between the
+    /// scan points are found as well.
+    ///
+    /// `sample_time` is `1.0 / sample_rate`.
+    pub fn peak_gain_db(&self, sample_time: f32) -> f32 {
+        10.0 * math::log10(self.peak_magnitude_squared(sample_time))
+    }
+
+    /// Returns the attenuation at `freq` in Hz relative to the peak gain in
+    /// dB, e.g. to verify the stopband of an anti-aliasing filter.
+    ///
+    /// The result is positive wherever the magnitude is below the peak gain
+    /// and is calculated as [`FilterCoefficients::peak_gain_db`] minus
+    /// [`FilterCoefficients::magnitude_db_at`].
+    ///
+    /// `sample_time` is `1.0 / sample_rate`.
+    pub fn attenuation_db_at(&self, freq: f32, sample_time: f32) -> f32 {
+        self.peak_gain_db(sample_time) - self.magnitude_db_at(freq, sample_time)
+    }
+
+    /// Returns the frequency in Hz at which the magnitude response is 3 dB
+    /// below the passband, or `None` if there is no single such frequency.
+    ///
+    /// The passband is taken at 0 Hz if the gain there is higher than at the
+    /// Nyquist frequency, as for a low-pass, or at the Nyquist frequency
+    /// otherwise, as for a high-pass. Shapes without a stopband at least 3 dB
+    /// below the passband, such as notch or all-pass, or with the passband
+    /// more than 40 dB below the peak of the response, such as band-pass,
+    /// return `None`. The same applies to responses crossing the -3 dB level
+    /// more than once.
+    ///
+    /// The response does not have to correspond to a [`crate::FilterType`],
+    /// e.g. coefficients interpolated between two filter types.
+    ///
+    /// `sample_time` is `1.0 / sample_rate`.
+    pub fn cutoff_3db(&self, sample_time: f32) -> Option<f32> {
+        let dc = self.magnitude_squared_at(0.0, sample_time);
+        let nyquist = self.magnitude_squared_at(0.5 / sample_time, sample_time);
+        let passband = dc.max(nyquist);
+        let level = passband / 2.0;
+
+        if dc.min(nyquist) >= level
+            || passband < self.peak_magnitude_squared(sample_time) * MIN_PASSBAND_RATIO
+        {
+            return None;
+        }
+
+        let below = |freq| self.magnitude_squared_at(freq, sample_time) < level;
+        let mut crossing = None;
+
+        for i in 1..SCAN_POINTS {
+            let lower = scan_frequency(i - 1, sample_time);
+            let upper = scan_frequency(i, sample_time);
+            if below(lower) != below(upper) {
+                if crossing.is_some() {
+                    return None;
+                }
+                crossing = Some((lower, upper));
+            }
+        }
+
+        let (mut lower, mut upper) = crossing?;
+        let lower_below = below(lower);
+
+        for _ in 0..BISECTION_STEPS {
+            let mid = 0.5 * (lower + upper);
+            if below(mid) == lower_below {
+                lower = mid;
+            } else {
+                upper = mid;
+            }
+        }
+
+        Some(0.5 * (lower + upper))
+    }
+
+    /// Returns the highest squared magnitude of the response.
+    fn peak_magnitude_squared(&self, sample_time: f32) -> f32 {
+        refined_maximum(sample_time, |freq| {
+            self.magnitude_squared_at(freq, sample_time)
+        })
+    }
+
+    /// Returns the local slope of the magnitude response at `freq` in Hz
+    /// in dB/octave.
+    ///
+    /// The slope is estimated by a central difference over a tenth of an
+    /// octave around `freq`. Unlike the asymptotic slope of the filter type,
+    /// this reflects the actual steepness near the cutoff frequency.
+    ///
+    /// `sample_time` is `1.0 / sample_rate`.
+    pub fn local_slope_db_oct(&self, freq: f32, sample_time: f32) -> f32 {
+        let ratio = F32Ext::powf(2.0, SLOPE_STEP_OCT / 2.0);
+        let upper = self.magnitude_db_at(freq * ratio, sample_time);
+        let lower = self.magnitude_db_at(freq / ratio, sample_time);
+        (upper - lower) / SLOPE_STEP_OCT
+    }
+}
+
+/// Returns `[sin(w), cos(w), sin(2w), cos(2w)]` for the angular frequency
+/// `w` corresponding to `freq` in Hz.
+///
+/// The values are derived from the half angle, which is in the range of the
+/// series expansions up to the Nyquist frequency.
+pub(crate) fn unit_circle(freq: f32, sample_time: f32) -> [f64; 4] {
+    let half = PI * freq as f64 * sample_time as f64;
+    let (sin1, cos1) = if half.abs() <= FRAC_PI_2 {
+        let (s, c) = (math::sin_f64(half), math::cos_f64(half));
+        (2.0 * s * c, c * c - s * s)
+    } else {
+        let (s, c) = F32Ext::sin_cos(2.0 * half as f32);
+        (s as f64, c as f64)
+    };
+
+    [sin1, cos1, 2.0 * sin1 * cos1, cos1 * cos1 - sin1 * sin1]
+}
+
+/// Returns the frequency in Hz of scan point `index` out of [`SCAN_POINTS`],
+/// spaced linearly from 0 Hz to the Nyquist frequency.
+pub(crate) fn scan_frequency(index: usize, sample_time: f32) -> f32 {
+    0.5 / sample_time * index as f32 / (SCAN_POINTS - 1) as f32
+}
+
+/// Returns the maximum of `value` as function of the frequency in Hz from
+/// 0 Hz to the Nyquist frequency.
+///
+/// The range is scanned at [`SCAN_POINTS`] frequencies and every local
+/// maximum is refined by a golden-section search between its neighboring
+/// scan points, so resonances narrower than the spacing of the scan are not
+/// underestimated.
+pub(crate) fn refined_maximum(sample_time: f32, value: impl Fn(f32) -> f32) -> f32 {
+    let mut maximum = f32::NEG_INFINITY;
+    let mut prev = f32::NEG_INFINITY;
+    let mut current = value(scan_frequency(0, sample_time));
+
+    for i in 0..SCAN_POINTS {
+        let next = if i + 1 < SCAN_POINTS {
+            value(scan_frequency(i + 1, sample_time))
+        } else {
+            f32::NEG_INFINITY
+        };
+
+        maximum = maximum.max(current);
+
+        let is_plateau = current == prev && current == next;
+        if current >= prev && current >= next && !is_plateau {
+            let lower = scan_frequency(i.saturating_sub(1), sample_time);
+            let upper = scan_frequency((i + 1).min(SCAN_POINTS - 1), sample_time);
+            maximum = maximum.max(golden_section_maximum(&value, lower, upper));
+        }
+
+        prev = current;
+        current = next;
+    }
+
+    maximum
+}
+
+/// Returns the maximum of the unimodal function `value` between `lower` and
+/// `upper`.
+fn golden_section_maximum(value: &impl Fn(f32) -> f32, mut lower: f32, mut upper: f32) -> f32 {
+    const INV_PHI: f32 = 0.618_034;
+
+    let mut x1 = upper - INV_PHI * (upper - lower);
+    let mut x2 = lower + INV_PHI * (upper - lower);
+    let (mut v1, mut v2) = (value(x1), value(x2));
+
+    for _ in 0..GOLDEN_SECTION_STEPS {
+        if v1 < v2 {
+            lower = x1;
+            (x1, v1) = (x2, v2);
+            x2 = lower + INV_PHI * (upper - lower);
+            v2 = value(x2);
+        } else {
+            upper = x2;
+            (x2, v2) = (x1, v1);
+            x1 = upper - INV_PHI * (upper - lower);
+            v1 = value(x1);
+        }
+    }
+
+    v1.max(v2)
+}
+
+/// Returns the response of sections in series at `freq` in Hz as magnitude
+/// in dB and phase in radians.
+///
+/// `sample_time` is `1.0 / sample_rate`.
+pub fn chain_response_at(coeffs: &[FilterCoefficients], freq: f32, sample_time: f32) -> (f32, f32) {
+    let response = coeffs
+        .iter()
+        .map(|coeffs| coeffs.response_at(freq, sample_time))
+        .fold(Complex::new(1.0, 0.0), |product, response| {
+            product * response
+        });
+
+    (
+        10.0 * math::log10(response.re * response.re + response.im * response.im),
+        F32Ext::atan2(response.im, response.re),
+    )
+}
+
+/// Returns the magnitude response of two filters in parallel at `freq` in
+/// Hz in dB, i.e. of their outputs summed.
+///
+/// `sample_time` is `1.0 / sample_rate`.
+pub fn summed_response_db(
+    a: &FilterCoefficients,
+    b: &FilterCoefficients,
+    freq: f32,
+    sample_time: f32,
+) -> f32 {
+    let a = a.response_at(freq, sample_time);
+    let b = b.response_at(freq, sample_time);
+    let (re, im) = (a.re + b.re, a.im + b.im);
+
+    10.0 * math::log10(re * re + im * im)
+}
+
+/// Returns if the outputs of `low` and `high` sum up to a flat magnitude
+/// response, deviating no more than `tol_db` from 0 dB.
+///
+/// The response is scanned at a fixed number of frequencies from 0 Hz to
+/// the Nyquist frequency.
+///
+/// `sample_time` is `1.0 / sample_rate`.
+pub fn are_complementary(
+    low: &FilterCoefficients,
+    high: &FilterCoefficients,
+    sample_time: f32,
+    tol_db: f32,
+) -> bool {
+    (0..SCAN_POINTS).all(|i| {
+        let freq = scan_frequency(i, sample_time);
+        summed_response_db(low, high, freq, sample_time).abs() <= tol_db
+    })
+}
+
+/// Returns the number of samples to delay the output of `b` to match the
+/// group delay of `a` at `at_freq` in Hz.
+///
+/// Negative values mean that `a` has to be delayed instead.
+///
+/// `sample_time` is `1.0 / sample_rate`.
+pub fn integer_delay_to_align(
+    a: &FilterCoefficients,
+    b: &FilterCoefficients,
+    at_freq: f32,
+    sample_time: f32,
+) -> i32 {
+    let difference =
+        a.group_delay_at(at_freq, sample_time) - b.group_delay_at(at_freq, sample_time);
+    F32Ext::round(difference) as i32
+}
@@ -0,0 +1,129 @@
+//! Filter with saturation.
+
+use micromath::F32Ext;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::FilterCoefficients;
+
+/// Saturation curves.
+///
+/// All curves have unity gain for small signals.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Saturation {
+    /// No saturation, linear behavior.
+    #[default]
+    None,
+
+    /// Hyperbolic tangent, limiting to ±1.
+    Tanh,
+
+    /// Hard clipping at ±1.
+    HardClip,
+
+    /// Cubic soft clipping, limiting to ±2/3.
+    Cubic,
+}
+
+impl Saturation {
+    /// Applies the saturation curve to a sample.
+    pub fn apply(&self, sample: f32) -> f32 {
+        match self {
+            Saturation::None => sample,
+            Saturation::Tanh => {
+                if sample.abs() > 9.0 {
+                    sample.signum()
+                } else {
+                    let e = F32Ext::exp(2.0 * sample);
+                    (e - 1.0) / (e + 1.0)
+                }
+            }
+            Saturation::HardClip => sample.clamp(-1.0, 1.0),
+            Saturation::Cubic => {
+                let x = sample.clamp(-1.0, 1.0);
+                x - x * x * x / 3.0
+            }
+        }
+    }
+}
+
+/// Direct form 1 with saturation applied to the output.
+#[derive(Debug, Default, Clone)]
+pub struct SaturatingFilter {
+    /// Coefficients.
+    coeffs: FilterCoefficients,
+
+    /// Saturation curve.
+    saturation: Saturation,
+
+    /// Whether the saturated output is fed back.
+    saturate_feedback: bool,
+
+    /// Input sample memory.
+    in_states: [f32; 2],
+
+    /// Output sample memory.
+    out_states: [f32; 2],
+}
+
+impl SaturatingFilter {
+    /// Returns a new instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resets the filter to bypass mode.
+    pub fn reset(&mut self) {
+        self.set_coefficients(FilterCoefficients::default());
+    }
+
+    /// Sets the coefficients.
+    pub fn set_coefficients(&mut self, coeffs: FilterCoefficients) {
+        self.coeffs = coeffs;
+    }
+
+    /// Sets the saturation curve.
+    pub fn set_saturation(&mut self, saturation: Saturation) {
+        self.saturation = saturation;
+    }
+
+    /// Sets whether the saturated output is also used as feedback state.
+    ///
+    /// When enabled, the saturation becomes part of the recursion, which
+    /// tames resonances at high levels. When disabled, only the output is
+    /// saturated and the filter itself stays linear.
+    pub fn set_saturate_feedback(&mut self, saturate_feedback: bool) {
+        self.saturate_feedback = saturate_feedback;
+    }
+
+    /// Processes a single sample.
+    pub fn process_sample(&mut self, sample: f32) -> f32 {
+        let linear = self.coeffs.a0 * sample
+            + self.coeffs.a1 * self.in_states[0]
+            + self.coeffs.a2 * self.in_states[1]
+            - self.coeffs.b1 * self.out_states[0]
+            - self.coeffs.b2 * self.out_states[1];
+        let out_sample = self.saturation.apply(linear);
+
+        self.in_states[1] = self.in_states[0];
+        self.in_states[0] = sample;
+
+        self.out_states[1] = self.out_states[0];
+        self.out_states[0] = if self.saturate_feedback {
+            out_sample
+        } else {
+            linear
+        };
+
+        out_sample
+    }
+
+    /// Processes a block of samples in-place.
+    pub fn process_block(&mut self, samples: &mut [f32]) {
+        for sample in samples.iter_mut() {
+            *sample = self.process_sample(*sample);
+        }
+    }
+}
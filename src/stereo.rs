@@ -0,0 +1,59 @@
+//! Stereo processing with linked channels.
+
+use crate::{DirectForm2Transposed, FilterCoefficients};
+
+/// Stereo filter keeping both channels coherent.
+///
+/// Instead of filtering left and right independently, the filter processes
+/// the mid (sum) and side (difference) signals and converts back. For a
+/// linear filter the result is identical, but identical input channels
+/// produce a side signal of exactly zero, so the output channels can never
+/// drift apart, even with heavy resonance.
+#[derive(Debug, Default, Clone)]
+pub struct LinkedStereoBiquad {
+    /// Filter for the mid signal.
+    mid: DirectForm2Transposed,
+
+    /// Filter for the side signal.
+    side: DirectForm2Transposed,
+}
+
+impl LinkedStereoBiquad {
+    /// Returns a new instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resets the filter to bypass mode.
+    pub fn reset(&mut self) {
+        self.mid.reset();
+        self.side.reset();
+    }
+
+    /// Sets the coefficients for both channels.
+    pub fn set_coefficients(&mut self, coeffs: FilterCoefficients) {
+        self.mid.set_coefficients(coeffs.clone());
+        self.side.set_coefficients(coeffs);
+    }
+
+    /// Processes a single frame, returning the left and right samples.
+    pub fn process_frame(&mut self, left: f32, right: f32) -> (f32, f32) {
+        let mid = self.mid.process_sample(0.5 * (left + right));
+        let side = self.side.process_sample(0.5 * (left - right));
+
+        (mid + side, mid - side)
+    }
+
+    /// Processes a block of frames in-place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `left` and `right` differ in length.
+    pub fn process_block(&mut self, left: &mut [f32], right: &mut [f32]) {
+        assert_eq!(left.len(), right.len());
+
+        for (left, right) in left.iter_mut().zip(right.iter_mut()) {
+            (*left, *right) = self.process_frame(*left, *right);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! Deterministic test vectors for validating other implementations.
+//!
+//! A canonical input signal is generated with [`reference_input`] and
+//! passed through both filter structures by [`write_csv`]. The resulting CSV
+//! contains the input alongside the outputs, so ports of the filters can be
+//! fed with exactly the same samples and compared against the outputs.
+
+use core::f32::consts::PI;
+use core::fmt::Write;
+
+use micromath::F32Ext;
+
+use crate::{DirectForm1, DirectForm2Transposed, FilterCoefficients, FilterType};
+
+/// Amplitude of the chirp section.
+const CHIRP_AMPLITUDE: f32 = 0.5;
+
+/// Start frequency of the chirp section in Hz.
+const CHIRP_START_FREQ: f32 = 20.0;
+
+/// End frequency of the chirp section relative to the sample rate.
+const CHIRP_END_RATIO: f32 = 0.45;
+
+/// Fills `buffer` with the canonical input signal.
+///
+/// The first half of the buffer contains a unit impulse followed by
+/// silence, the second half a linear sine chirp sweeping from 20 Hz to
+/// 45% of the sample rate.
+///
+/// `sample_time` is `1.0 / sample_rate`.
+pub fn reference_input(buffer: &mut [f32], sample_time: f32) {
+    let (impulse, chirp) = buffer.split_at_mut(buffer.len() / 2);
+
+    impulse.fill(0.0);
+    if let Some(first) = impulse.first_mut() {
+        *first = 1.0;
+    }
+
+    let end_freq = CHIRP_END_RATIO / sample_time;
+    let freq_step = (end_freq - CHIRP_START_FREQ) / chirp.len() as f32;
+    let mut freq = CHIRP_START_FREQ;
+    let mut phase = 0.0;
+
+    for sample in chirp.iter_mut() {
+        *sample = CHIRP_AMPLITUDE * F32Ext::sin(phase);
+        phase += 2.0 * PI * freq * sample_time;
+        if phase >= PI {
+            phase -= 2.0 * PI;
+        }
+        freq += freq_step;
+    }
+}
+
+/// Writes the test vectors for `filter_type` as CSV to `writer`.
+///
+/// The columns are `index`, `input`, `direct_form_1` and
+/// `direct_form_2_transposed`. Values are formatted with the shortest
+/// representation that parses back to the identical `f32`, so comparisons
+/// can be done bit-exact.
+///
+/// `input` is usually generated with [`reference_input`].
+///
+/// `sample_time` is `1.0 / sample_rate`.
+pub fn write_csv<W: Write>(
+    writer: &mut W,
+    filter_type: FilterType,
+    sample_time: f32,
+    input: &[f32],
+) -> core::fmt::Result {
+    let coeffs = FilterCoefficients::from_type(filter_type, sample_time);
+
+    let mut df1 = DirectForm1::new();
+    df1.set_coefficients(coeffs.clone());
+
+    let mut df2t = DirectForm2Transposed::new();
+    df2t.set_coefficients(coeffs);
+
+    writeln!(writer, "index,input,direct_form_1,direct_form_2_transposed")?;
+
+    for (index, sample) in input.iter().enumerate() {
+        writeln!(
+            writer,
+            "{},{},{},{}",
+            index,
+            sample,
+            df1.process_sample(*sample),
+            df2t.process_sample(*sample)
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+//! Spectral tilt estimation.
+
+use crate::{math, DirectForm2Transposed, FilterCoefficients, FilterType};
+
+/// Q value of the band-pass filters.
+const BAND_Q: f32 = 0.7;
+
+/// Estimator for the spectral balance between a low and a high band.
+///
+/// The signal is split by two band-pass filters and the energy of both
+/// outputs is accumulated until [`TiltEstimator::reset`] is called. The
+/// ratio of the energies is a cheap alternative to a spectrum analysis,
+/// e.g. for adaptive EQ.
+#[derive(Debug, Clone)]
+pub struct TiltEstimator {
+    /// Band-pass for the low band.
+    low: DirectForm2Transposed,
+
+    /// Band-pass for the high band.
+    high: DirectForm2Transposed,
+
+    /// Accumulated energy of the low band.
+    low_energy: f32,
+
+    /// Accumulated energy of the high band.
+    high_energy: f32,
+}
+
+impl TiltEstimator {
+    /// Returns a new instance with bands centered at `low_freq` and
+    /// `high_freq` in Hz.
+    ///
+    /// `sample_time` is `1.0 / sample_rate`.
+    pub fn new(low_freq: f32, high_freq: f32, sample_time: f32) -> Self {
+        let band = |freq| {
+            let mut filter = DirectForm2Transposed::new();
+            filter.set_coefficients(FilterCoefficients::from_type(
+                FilterType::BandPass { freq, q: BAND_Q },
+                sample_time,
+            ));
+            filter
+        };
+
+        Self {
+            low: band(low_freq),
+            high: band(high_freq),
+            low_energy: 0.0,
+            high_energy: 0.0,
+        }
+    }
+
+    /// Clears the accumulated energies to start a new measurement.
+    pub fn reset(&mut self) {
+        self.low_energy = 0.0;
+        self.high_energy = 0.0;
+    }
+
+    /// Accumulates a block of samples.
+    pub fn process_block(&mut self, samples: &[f32]) {
+        for sample in samples {
+            let low = self.low.process_sample(*sample);
+            let high = self.high.process_sample(*sample);
+            self.low_energy += low * low;
+            self.high_energy += high * high;
+        }
+    }
+
+    /// Returns the energy ratio of the high to the low band in dB.
+    ///
+    /// Positive values indicate a treble-heavy, negative values a
+    /// bass-heavy signal. Returns 0.0 if no energy was accumulated.
+    pub fn tilt(&self) -> f32 {
+        if self.low_energy == 0.0 && self.high_energy == 0.0 {
+            return 0.0;
+        }
+
+        10.0 * (math::log10(self.high_energy) - math::log10(self.low_energy))
+    }
+}
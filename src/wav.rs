@@ -0,0 +1,71 @@
+//! Export of impulse responses as WAV files.
+
+extern crate std;
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use crate::{DirectForm2Transposed, FilterCoefficients};
+
+/// WAV format tag for IEEE float samples.
+const FORMAT_IEEE_FLOAT: u16 = 3;
+
+/// Size of a sample in bytes.
+const SAMPLE_SIZE: u32 = 4;
+
+impl FilterCoefficients {
+    /// Writes the first `len_samples` samples of the impulse response to a
+    /// mono 32-bit float WAV file at `path`.
+    ///
+    /// The file can be loaded as impulse response into convolution tools.
+    /// `sample_rate` is only stored in the header and should match the rate
+    /// the coefficients were calculated for.
+    pub fn write_impulse_wav(
+        &self,
+        path: impl AsRef<Path>,
+        len_samples: u32,
+        sample_rate: u32,
+    ) -> io::Result<()> {
+        let data_size = len_samples
+            .checked_mul(SAMPLE_SIZE)
+            .filter(|size| *size <= u32::MAX - 50)
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidInput, "length exceeds WAV limit")
+            })?;
+
+        let mut writer = BufWriter::new(File::create(path)?);
+
+        writer.write_all(b"RIFF")?;
+        writer.write_all(&(50 + data_size).to_le_bytes())?;
+        writer.write_all(b"WAVE")?;
+
+        // Non-PCM formats require the extension size field and a fact chunk.
+        writer.write_all(b"fmt ")?;
+        writer.write_all(&18u32.to_le_bytes())?;
+        writer.write_all(&FORMAT_IEEE_FLOAT.to_le_bytes())?;
+        writer.write_all(&1u16.to_le_bytes())?;
+        writer.write_all(&sample_rate.to_le_bytes())?;
+        writer.write_all(&(sample_rate * SAMPLE_SIZE).to_le_bytes())?;
+        writer.write_all(&(SAMPLE_SIZE as u16).to_le_bytes())?;
+        writer.write_all(&(8 * SAMPLE_SIZE as u16).to_le_bytes())?;
+        writer.write_all(&0u16.to_le_bytes())?;
+
+        writer.write_all(b"fact")?;
+        writer.write_all(&4u32.to_le_bytes())?;
+        writer.write_all(&len_samples.to_le_bytes())?;
+
+        writer.write_all(b"data")?;
+        writer.write_all(&data_size.to_le_bytes())?;
+
+        let mut filter = DirectForm2Transposed::new();
+        filter.set_coefficients(self.clone());
+
+        for n in 0..len_samples {
+            let input = if n == 0 { 1.0 } else { 0.0 };
+            writer.write_all(&filter.process_sample(input).to_le_bytes())?;
+        }
+
+        writer.flush()
+    }
+}
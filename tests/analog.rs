@@ -0,0 +1,73 @@
+//! Tests for coefficients derived from analog component values.
+
+use core::f32::consts::PI;
+
+use biquad_micromath::FilterCoefficients;
+
+#[test]
+fn rc_lowpass_corner_frequency() {
+    let sample_time = 1.0 / 48000.0;
+
+    for (r, c) in [(1.0e3, 100.0e-9), (10.0e3, 10.0e-9), (4.7e3, 1.0e-6)] {
+        let corner = 1.0 / (2.0 * PI * r * c);
+        let coeffs = FilterCoefficients::from_rc_lowpass(r, c, sample_time);
+
+        let gain = coeffs.magnitude_db_at(corner, sample_time);
+        assert!(
+            (gain + 3.0103).abs() < 0.01,
+            "gain {gain} dB at {corner} Hz, expected -3.01 dB"
+        );
+    }
+}
+
+#[test]
+fn rc_highpass_corner_frequency() {
+    let sample_time = 1.0 / 48000.0;
+    let (r, c) = (1.0e3, 1.0e-6);
+    let corner = 1.0 / (2.0 * PI * r * c);
+    let coeffs = FilterCoefficients::from_rc_highpass(r, c, sample_time);
+
+    let gain = coeffs.magnitude_db_at(corner, sample_time);
+    assert!(
+        (gain + 3.0103).abs() < 0.01,
+        "gain {gain} dB at {corner} Hz, expected -3.01 dB"
+    );
+}
+
+#[test]
+fn rlc_resonance_gain() {
+    let sample_time = 1.0 / 48000.0;
+    let (r, l, c): (f32, f32, f32) = (10.0, 10.0e-3, 1.0e-6);
+    let resonance = 1.0 / (2.0 * PI * (l * c).sqrt());
+    let q = (l / c).sqrt() / r;
+    let coeffs = FilterCoefficients::from_rlc(r, l, c, sample_time);
+
+    // At resonance, the voltage across the capacitor is Q times the input.
+    let gain = coeffs.magnitude_db_at(resonance, sample_time);
+    let expected = 20.0 * q.log10();
+    assert!(
+        (gain - expected).abs() < 0.05,
+        "gain {gain} dB at {resonance} Hz, expected {expected} dB"
+    );
+}
+
+#[test]
+fn corner_above_nyquist() {
+    let sample_time = 1.0 / 48000.0;
+
+    for (r, c) in [(1.0e3, 4.7e-9), (2.2e3, 1.0e-9), (10.0, 1.0e-9)] {
+        let lowpass = FilterCoefficients::from_rc_lowpass(r, c, sample_time);
+        assert!(lowpass.is_stable(), "unstable low-pass for R {r}, C {c}");
+        let gain = lowpass.magnitude_db_at(20000.0, sample_time);
+        assert!(gain > -3.0, "low-pass gain {gain} dB at 20 kHz");
+
+        let highpass = FilterCoefficients::from_rc_highpass(r, c, sample_time);
+        assert!(highpass.is_stable(), "unstable high-pass for R {r}, C {c}");
+    }
+
+    // Resonance at 50 kHz, so the audio range is the flat passband.
+    let rlc = FilterCoefficients::from_rlc(100.0, 1.0e-3, 10.0e-9, sample_time);
+    assert!(rlc.is_stable());
+    let gain = rlc.magnitude_db_at(5000.0, sample_time);
+    assert!(gain.abs() < 0.5, "gain {gain} dB at 5 kHz");
+}
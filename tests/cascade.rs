@@ -0,0 +1,41 @@
+//! Cascade tests.
+
+use biquad_micromath::{Cascade, DirectForm2Transposed, FilterCoefficients, FilterType};
+
+#[test]
+fn balanced_scales_limit_resonance() {
+    let sample_rate = 48000.0;
+    let sample_time = 1.0 / sample_rate;
+
+    for (freq, q) in [(100.0, 10.0), (1000.0, 30.0), (15000.0, 5.0)] {
+        let resonant = FilterCoefficients::from_type(FilterType::LowPass { freq, q }, sample_time);
+        let mut cascade = Cascade::<2>::new();
+        cascade.set_coefficients(0, resonant.clone());
+        cascade.set_coefficients(
+            1,
+            FilterCoefficients::from_type(FilterType::HighPass { freq: 20.0, q: 0.7 }, sample_time),
+        );
+        cascade.balance_scales(sample_time);
+
+        // A sine at the resonance peak of the low-pass.
+        let peak_freq = freq as f64 * (1.0 - 1.0 / (2.0 * q as f64 * q as f64)).sqrt();
+        let mut section = DirectForm2Transposed::new();
+        section.set_coefficients(resonant);
+
+        let len = sample_rate as usize * 2;
+        let mut max = 0.0f32;
+        for n in 0..len {
+            let phase = 2.0 * core::f64::consts::PI * peak_freq * n as f64 / sample_rate as f64;
+            let out = section.process_sample(phase.sin() as f32) * cascade.section_scale(0);
+            if n > len / 2 {
+                max = max.max(out.abs());
+            }
+        }
+
+        assert!(
+            max < 1.01,
+            "intermediate signal peaks at {max} for low-pass at {freq} Hz with Q {q}"
+        );
+        assert!(max > 0.95, "intermediate signal only peaks at {max}");
+    }
+}
@@ -0,0 +1,46 @@
+//! Discontinuity handling tests.
+
+use biquad_micromath::{DirectForm1, FilterCoefficients, FilterType};
+
+fn lowpass(threshold: Option<f32>) -> DirectForm1 {
+    let sample_time = 1.0 / 48000.0;
+    let mut filter = DirectForm1::new();
+    filter.set_coefficients(FilterCoefficients::from_type(
+        FilterType::LowPass {
+            freq: 1000.0,
+            q: 0.707,
+        },
+        sample_time,
+    ));
+    filter.set_discontinuity_threshold(threshold);
+    filter
+}
+
+#[test]
+fn first_block_is_not_a_discontinuity() {
+    let mut reference = [1.0; 64];
+    lowpass(None).process_block(&mut reference);
+
+    let mut samples = [1.0; 64];
+    lowpass(Some(0.5)).process_block(&mut samples);
+
+    assert_eq!(samples, reference);
+}
+
+#[test]
+fn jump_between_blocks_is_crossfaded() {
+    let mut filter = lowpass(Some(0.5));
+    let mut reference = lowpass(None);
+
+    let mut samples = [0.0; 4800];
+    filter.process_block(&mut samples);
+    reference.process_block(&mut samples);
+
+    let mut samples = [1.0; 64];
+    let mut reference_samples = [1.0; 64];
+    filter.process_block(&mut samples);
+    reference.process_block(&mut reference_samples);
+
+    assert_ne!(samples, reference_samples);
+    assert!((samples[63] - 1.0).abs() < 1e-3);
+}
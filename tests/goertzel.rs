@@ -0,0 +1,27 @@
+//! Goertzel detector tests.
+
+use biquad_micromath::Goertzel;
+
+#[test]
+fn tone_magnitude() {
+    let sample_rate = 48000.0;
+    let sample_time = 1.0 / sample_rate;
+    let len = 4800;
+    let amplitude = 0.5;
+
+    for freq in [100.0, 1000.0, 5000.0, 12000.0, 20000.0] {
+        let mut goertzel = Goertzel::new(freq, sample_time);
+
+        for n in 0..len {
+            let phase = 2.0 * core::f64::consts::PI * freq as f64 * n as f64 / sample_rate as f64;
+            goertzel.process_sample((amplitude * phase.sin()) as f32);
+        }
+
+        let expected = amplitude as f32 * len as f32 / 2.0;
+        let magnitude = goertzel.magnitude();
+        assert!(
+            (magnitude - expected).abs() / expected < 0.01,
+            "magnitude {magnitude} at {freq} Hz, expected {expected}"
+        );
+    }
+}
@@ -0,0 +1,19 @@
+//! Polarity inversion tests.
+
+use biquad_micromath::{DirectForm1, DirectForm2Transposed, FilterCoefficients, FilterType};
+
+#[test]
+fn invert_negates_input() {
+    let coeffs = FilterCoefficients::from_type(FilterType::Invert, 1.0 / 48000.0);
+    let input = [1.0, -0.5, 0.25, 0.0, 0.75, -1.0];
+
+    let mut df1 = DirectForm1::new();
+    df1.set_coefficients(coeffs.clone());
+    let mut df2t = DirectForm2Transposed::new();
+    df2t.set_coefficients(coeffs);
+
+    for sample in input {
+        assert_eq!(df1.process_sample(sample), -sample);
+        assert_eq!(df2t.process_sample(sample), -sample);
+    }
+}
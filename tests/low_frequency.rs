@@ -0,0 +1,22 @@
+//! Accuracy tests for low cutoff frequencies.
+
+use biquad_micromath::{FilterCoefficients, FilterType};
+
+#[test]
+fn first_order_lowpass_cutoff_accuracy() {
+    let sample_time = 1.0 / 48000.0;
+
+    for freq in [0.1, 1.0, 10.0, 100.0, 1000.0] {
+        let coeffs =
+            FilterCoefficients::from_type(FilterType::FirstOrderLowPass { freq }, sample_time);
+
+        // The first tap is k / (1 + k) with k = tan(PI * freq * sample_time).
+        let mut taps = [0.0];
+        coeffs.impulse_taps(&mut taps);
+        let k = taps[0] as f64 / (1.0 - taps[0] as f64);
+        let realized = k.atan() / (core::f64::consts::PI * sample_time as f64);
+
+        let error = (realized - freq as f64).abs() / freq as f64;
+        assert!(error < 1e-3, "cutoff {realized} Hz, expected {freq} Hz");
+    }
+}
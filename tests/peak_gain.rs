@@ -0,0 +1,30 @@
+//! Peak gain and attenuation tests.
+
+use biquad_micromath::{FilterCoefficients, FilterType};
+
+#[test]
+fn peaking_eq_peak_gain() {
+    let sample_time = 1.0 / 48000.0;
+
+    for (freq, q) in [(100.0, 10.0), (1000.0, 1.0), (1234.5, 30.0), (10000.0, 2.0)] {
+        let coeffs = FilterCoefficients::from_type(
+            FilterType::PeakingEq {
+                freq,
+                q,
+                gain: 12.0,
+            },
+            sample_time,
+        );
+
+        let peak = coeffs.peak_gain_db(sample_time);
+        assert!(
+            (peak - 12.0).abs() < 0.05,
+            "peak gain {peak} dB at {freq} Hz with Q {q}"
+        );
+        let attenuation = coeffs.attenuation_db_at(freq, sample_time);
+        assert!(
+            attenuation.abs() < 0.05,
+            "attenuation {attenuation} dB at peak"
+        );
+    }
+}
@@ -0,0 +1,25 @@
+//! Phaser tests.
+
+use biquad_micromath::Phaser;
+
+#[test]
+fn stages_above_nyquist_stay_stable() {
+    let sample_time = 1.0 / 48000.0;
+    let mut phaser = Phaser::<4>::new(sample_time);
+    phaser.set_spread(1.5);
+
+    for center in [10000.0, 20000.0, 30000.0] {
+        phaser.set_center(center);
+
+        let mut samples = [0.0; 4800];
+        samples[0] = 1.0;
+        phaser.process_block(&mut samples);
+
+        for sample in samples {
+            assert!(
+                sample.abs() <= 1.0,
+                "output {sample} for center frequency {center} Hz"
+            );
+        }
+    }
+}
@@ -0,0 +1,34 @@
+//! Resonator bank tests.
+
+use biquad_micromath::{resonator_bank_from_notes, FilterCoefficients};
+
+#[test]
+fn unity_gain_at_note_frequency() {
+    let sample_time = 1.0 / 48000.0;
+    // Below A0, the precision of the coefficients is not sufficient for
+    // long decays.
+    let notes: Vec<u8> = (21..=127).collect();
+    let mut coeffs = vec![FilterCoefficients::default(); notes.len()];
+
+    for decay_seconds in [0.1, 1.0] {
+        resonator_bank_from_notes(&notes, decay_seconds, sample_time, &mut coeffs);
+
+        for (note, coeffs) in notes.iter().zip(coeffs.iter()) {
+            let freq = 440.0 * 2.0f32.powf((*note as f32 - 69.0) / 12.0);
+            let gain = coeffs.magnitude_db_at(freq, sample_time);
+            assert!(gain.abs() < 0.1, "gain {gain} dB for note {note}");
+        }
+    }
+}
+
+#[test]
+fn resonator_peak_gain() {
+    let sample_time = 1.0 / 48000.0;
+    let mut coeffs = [FilterCoefficients::default(), FilterCoefficients::default()];
+    resonator_bank_from_notes(&[69, 100], 1.0, sample_time, &mut coeffs);
+
+    for coeffs in coeffs.iter() {
+        let peak = coeffs.peak_gain_db(sample_time);
+        assert!(peak.abs() < 0.05, "peak gain {peak} dB");
+    }
+}
@@ -0,0 +1,34 @@
+//! Step response tests.
+
+use biquad_micromath::{DirectForm1, FilterCoefficients, FilterType};
+
+#[test]
+fn first_order_lowpass_rise_time() {
+    let sample_rate = 48000.0;
+    let sample_time = 1.0 / sample_rate;
+
+    for rise_time in [0.001, 0.01, 0.1] {
+        let filter_type = FilterType::first_order_lowpass_for_rise_time(rise_time, sample_time);
+        let mut filter = DirectForm1::new();
+        filter.set_coefficients(FilterCoefficients::from_type(filter_type, sample_time));
+
+        let mut start = None;
+        let mut end = None;
+
+        for n in 0..(rise_time * sample_rate * 10.0) as usize {
+            let out = filter.process_sample(1.0);
+            if start.is_none() && out >= 0.1 {
+                start = Some(n);
+            }
+            if end.is_none() && out >= 0.9 {
+                end = Some(n);
+            }
+        }
+
+        let measured = (end.unwrap() - start.unwrap()) as f32 * sample_time;
+        assert!(
+            (measured - rise_time).abs() / rise_time < 0.05,
+            "rise time {measured} s, expected {rise_time} s"
+        );
+    }
+}